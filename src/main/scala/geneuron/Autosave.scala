@@ -0,0 +1,33 @@
+package geneuron
+
+import java.io.File
+
+/** Snapshots the world into dir every interval simulated seconds, keeping only the newest keep snapshots. */
+class Autosave(val dir: String, val interval: Float, val keep: Int, start: Float) {
+  if (interval <= 0.0F || keep < 1) throw new IllegalArgumentException("Requirement: positive autosave interval and kept count")
+  private val Name = """geneuron-(\d+)\.json""".r
+  private var due = start + interval
+
+  def update(geneuron: Geneuron): Unit = {
+    if (due <= geneuron.time) {
+      due += interval
+      save(geneuron)
+    }
+  }
+
+  /** Schedules the next snapshot one interval after start, as when a loaded world resets the clock. */
+  def rebase(start: Float): Unit = {
+    due = start + interval
+  }
+
+  def save(geneuron: Geneuron): Unit = {
+    new File(dir).mkdirs()
+    Snapshot.save(new File(dir, "geneuron-%012d.json".format(geneuron.frame)).getPath, geneuron)
+    snapshots.dropRight(keep).foreach(_.delete())
+  }
+
+  /** Snapshots in dir, oldest first. */
+  def snapshots: Seq[File] = {
+    Option(new File(dir).listFiles).fold(Seq.empty[File])(_.toSeq).filter(f => Name.pattern.matcher(f.getName).matches).sortBy(_.getName)
+  }
+}
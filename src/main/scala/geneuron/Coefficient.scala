@@ -4,4 +4,30 @@ object Coefficient {
   val sight = 100.0F
   val angle = 100.0F
   val prop  = 100.0F
+  val colorLerp = 0.1F
+  val lodInterval = 4
+  val maxEnergy = 1.0F
+  val energyBarWidth = 20.0F
+  val energyBarZoom = 0.5F
+  val expectedLifespan = 3600L
+  val mutationRate = 0.05
+  val mutationRateSigma = 0.01
+  val minMutationRate = 0.001
+  val maxMutationRate = 0.5
+  val speciationInterval = 60
+  val spawnAttempts = 20
+  val maxSpeed = 10.0F
+  val minTimeScale = 0.125F
+  val maxTimeScale = 16.0F
+  val historyCapacity = 10000
+  val autosaveInterval = 600.0F
+  val autosaveKeep = 5
+  val graphSamples = 600
+  val graphWidth = 200.0F
+  val graphHeight = 80.0F
+  val heatmapBins = 16
+  val minimapWidth = 160.0F
+  val minZoom = 0.1F
+  val maxZoom = 4.0F
+  val autoZoomMargin = 20.0F
 }
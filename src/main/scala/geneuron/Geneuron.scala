@@ -3,52 +3,214 @@ package geneuron
 import java.util.logging.{Level, Logger}
 
 import geneuron.creature.{Creature, Physics}
-import geneuron.info.Notification
+import geneuron.info._
+import geneuron.neuron.{Genome, Speciation}
 import org.newdawn.slick._
 
 import scala.collection.parallel.mutable
-import scala.util.Random
+import scala.util.{Random, Try}
 
 object Geneuron {
+  val logger = Logger.getLogger(classOf[Geneuron].getName)
+  val snapshotPath = "geneuron.json"
+  val statsPath = "geneuron-stats.csv"
+  val screenshotDir = "screenshots"
   def main(args: Array[String]) {
     try {
-      val appgc = new AppGameContainer(new Geneuron("Geneuron"))
-      appgc.setDisplayMode(640, 480, false)
+      val config = WorldConfig.fromProperties()
+      val appgc = new AppGameContainer(new Geneuron("Geneuron", args.headOption, sys.props.get("geneuron.seed").map(_.toLong), config))
+      appgc.setDisplayMode(config.width, config.height, false)
       appgc.start()
     } catch {
-      case ex: SlickException => Logger.getLogger(classOf[Geneuron].getName).log(Level.SEVERE, null, ex)
+      case ex: SlickException => logger.log(Level.SEVERE, null, ex)
     }
   }
 }
 
-class Geneuron(gamename: String) extends BasicGame(gamename) {
+class Geneuron(gamename: String, initialGenomePath: Option[String] = None, seed: Option[Long] = None, val config: WorldConfig = WorldConfig()) extends BasicGame(gamename) {
+  var random: Random = seed.fold(new Random)(new Random(_))
   val creatures: mutable.ParSet[Creature] = mutable.ParSet.empty
   val viewpoint: ViewPoint = new ViewPoint
   var terminate: Boolean = false
+  var captureRequested: Boolean = false
+  var frame: Long = 0L
+  var elapsed: Float = 0.0F
+  /** Simulated seconds, advanced by every step regardless of pause, time scale or frame rate. */
+  var time: Float = 0.0F
+  var timeScale: Float = 1.0F
+  var paused: Boolean = false
+  var pendingSteps: Float = 0.0F
+  var obstacles: List[Obstacle] = Nil
+  var lastId: Long = 0L
+  var species: Map[Long, Int] = Map.empty
+  val history: StatsHistory = new StatsHistory(Coefficient.historyCapacity)
+  var autosave: Option[Autosave] = None
+  def statsHistory: Seq[StatsSample] = history.toSeq
+  def exportStatsCsv(path: String): Unit = history.exportCsv(path)
   def init(gc: GameContainer): Unit = {
     gc.setShowFPS(false)
-    creatures ++= (1 to 10) map { _ =>
-      new Creature(new Physics(Random.nextFloat(), Random.nextFloat(), Random.nextFloat(), Random.nextFloat(), Random.nextFloat(), Random.nextFloat()))
+    gc.setTargetFrameRate(config.renderFps)
+    populate()
+  }
+  /** Fraction of the day/night cycle elapsed, starting at midnight. */
+  def timeOfDay: Float = (frame % config.dayLength).toFloat / config.dayLength
+  /** 0 at midnight rising to 1 at noon. */
+  def daylight: Float = (0.5 - 0.5 * math.cos(2 * math.Pi * timeOfDay)).toFloat
+  def nextId(): Long = {
+    lastId += 1
+    lastId
+  }
+  /** Every living creature's parents, as child id to (parent id, parent id). */
+  def lineage: Seq[(Long, (Option[Long], Option[Long]))] = creatures.seq.toSeq.map(c => (c.id, c.parents)).sortBy(_._1)
+  def populate(): Unit = {
+    val initialGenome = initialGenomePath.map(Genome.load)
+    initialGenome.foreach { genome =>
+      val length = Creature.geneLength(config.sensors)
+      if (genome.length != length) throw new IllegalArgumentException(s"Requirement: initial genome length equals $length")
+    }
+    creatures ++= (1 to config.initialPopulation).foldLeft(List.empty[Creature]) { (placed, _) =>
+      val (x, y) = spawnPosition(placed)
+      val physics = new Physics(x, y, random.nextFloat(), random.nextFloat(), random.nextFloat(), random.nextFloat())
+      val creatureRandom = new Random(random.nextLong())
+      val gene = initialGenome.fold(Creature.randomGene(creatureRandom, config.sensors))(_.clone())
+      new Creature(physics, gene, creatureRandom, config)(nextId(), maxAge = config.lifespan) :: placed
     }
   }
+  def spawnPosition(placed: Seq[Creature]): (Float, Float) = {
+    def clearance(p: (Float, Float)): Float = placed.map { c =>
+      math.hypot(c.physics.x - p._1, c.physics.y - p._2).toFloat
+    }.foldLeft(Float.MaxValue)((a, b) => math.min(a, b))
+    val candidates = Seq.fill(Coefficient.spawnAttempts)((random.nextFloat() * config.width, random.nextFloat() * config.height))
+    candidates.find(config.spawnDistance <= clearance(_)).getOrElse(candidates.maxBy(clearance))
+  }
   def update(gc: GameContainer, i: Int): Unit = {
     val kd = gc.getInput.isKeyDown _
     val kp = gc.getInput.isKeyPressed _
     if (kd(Input.KEY_ESCAPE)) terminate = true
-    if (kd(Input.KEY_Z)) viewpoint.zoom *= 1.05F
-    if (kd(Input.KEY_X)) viewpoint.zoom *= 0.95F
+    val (mx, my) = (gc.getInput.getMouseX.toFloat, gc.getInput.getMouseY.toFloat)
+    if (kd(Input.KEY_Z)) viewpoint.zoomAt(viewpoint.zoom * 1.05F, mx, my)
+    if (kd(Input.KEY_X)) viewpoint.zoomAt(viewpoint.zoom * 0.95F, mx, my)
+    if (gc.getInput.isMousePressed(Input.MOUSE_LEFT_BUTTON)) {
+      (if (viewpoint.hud && viewpoint.minimap) Minimap.toWorld(gc, this, mx, my) else None) match {
+        case Some((wx, wy)) => viewpoint.center(gc.getWidth, gc.getHeight, wx, wy)
+        case None => viewpoint.selected = pickCreatureAt(mx, my).map(_.id)
+      }
+    }
+    if (kp(Input.KEY_C)) viewpoint.smoothColor = !viewpoint.smoothColor
+    if (kp(Input.KEY_M)) viewpoint.nextColorMode()
+    if (kp(Input.KEY_L)) viewpoint.lod = !viewpoint.lod
+    if (kp(Input.KEY_E)) viewpoint.energyBar = !viewpoint.energyBar
+    if (kp(Input.KEY_H)) viewpoint.hud = !viewpoint.hud
+    if (kp(Input.KEY_TAB)) viewpoint.statsPanel = !viewpoint.statsPanel
+    if (kp(Input.KEY_P)) viewpoint.nextStatsCorner()
+    if (kp(Input.KEY_A)) viewpoint.autoZoom = !viewpoint.autoZoom
+    if (kp(Input.KEY_O)) viewpoint.spread = !viewpoint.spread
+    if (kp(Input.KEY_G)) viewpoint.graph = !viewpoint.graph
+    if (kp(Input.KEY_N)) viewpoint.minimap = !viewpoint.minimap
+    if (kp(Input.KEY_K)) viewpoint.setHeatmap(if (viewpoint.heatmap.isEmpty) Some(Heatmap.Creatures) else None)
+    if (kp(Input.KEY_F5)) attempt("Saving " + Geneuron.snapshotPath)(Snapshot.save(Geneuron.snapshotPath, this))
+    if (kp(Input.KEY_F9)) attempt("Loading " + Geneuron.snapshotPath)(Snapshot.load(Geneuron.snapshotPath, this))
+    if (kp(Input.KEY_F6)) attempt("Exporting " + Geneuron.statsPath)(exportStatsCsv(Geneuron.statsPath))
+    if (kp(Input.KEY_F12)) captureRequested = true
+    if (kp(Input.KEY_EQUALS)) viewpoint.uiScale = math.min(4.0F, viewpoint.uiScale * 2)
+    if (kp(Input.KEY_MINUS)) viewpoint.uiScale = math.max(1.0F, viewpoint.uiScale / 2)
+    if (kp(Input.KEY_SPACE)) togglePause()
+    if (kp(Input.KEY_PERIOD)) stepOnce()
+    if (kp(Input.KEY_RBRACKET)) setTimeScale(timeScale * 2)
+    if (kp(Input.KEY_LBRACKET)) setTimeScale(timeScale / 2)
+    advance()
+    elapsed += i / 1000.0F
+    if (viewpoint.cameraPath.nonEmpty) viewpoint.tour(elapsed)
+    else if (viewpoint.autoZoom) viewpoint.frame(gc, creatures.seq.toSeq.map(c => (c.physics.x, c.physics.y)))
+  }
+  /** Runs a file action, logging a failure instead of letting it end the game. */
+  def attempt(action: String)(f: => Unit): Unit = {
+    Try(f).failed.foreach(ex => Geneuron.logger.log(Level.WARNING, action + " failed", ex))
+  }
+  /** Runs the steps owed for one rendered frame at the current time scale, carrying fractional steps over. */
+  def advance(): Unit = {
+    if (!paused) {
+      pendingSteps += config.stepsPerFrame * timeScale
+      while (1.0F <= pendingSteps) {
+        step()
+        pendingSteps -= 1.0F
+      }
+    }
+  }
+  def enableAutosave(dir: String, interval: Float = Coefficient.autosaveInterval, keep: Int = Coefficient.autosaveKeep): Unit = {
+    autosave = Some(new Autosave(dir, interval, keep, time))
+  }
+  def setTimeScale(scale: Float): Unit = {
+    timeScale = math.max(Coefficient.minTimeScale, math.min(Coefficient.maxTimeScale, scale))
+  }
+  def togglePause(): Unit = {
+    paused = !paused
+  }
+  def stepOnce(): Unit = {
+    step()
+  }
+  /** Nearest creature whose body contains the screen position (sx, sy). */
+  def pickCreatureAt(sx: Float, sy: Float): Option[Creature] = {
+    val (wx, wy) = viewpoint.toWorld(sx, sy)
+    val hits = creatures.seq.toSeq.map(c => (c, math.hypot(c.physics.x - wx, c.physics.y - wy))).filter {
+      case (c, d) => d <= c.circle.getRadius
+    }
+    if (hits.isEmpty) None else Some(hits.minBy(_._2)._1)
+  }
+  def regroup(): Unit = {
+    species = Speciation.assign(creatures.seq.toSeq.sortBy(_.id).map(c => (c.id, c.gene)), config.speciesThreshold)
+  }
+  def addObstacle(obstacle: Obstacle): Unit = {
+    obstacles ::= obstacle
+  }
+  def step(): Unit = {
+    frame += 1
+    time += config.stepTime
+    val night = 1.0F - daylight
+    val sight = Coefficient.sight * (1.0F - config.nightBlindness * night)
+    val metabolism = 1.0F + config.nightMetabolism * night
+    val positions = if (0 < config.sensors.vision) creatures.seq.toSeq.map(c => (c.id, c.physics.x, c.physics.y)) else Nil
     creatures.foreach { creature =>
+      creature.age += 1
       creature.physics.update()
-      creature.process(Array.fill(29)(Random.nextFloat()))
+      obstacles.foreach(_.collide(creature.physics, creature.circle.getRadius))
+      val skip = !creature.senseDue(frame, config.senseInterval) ||
+        viewpoint.lod && frame % Coefficient.lodInterval != 0 && !viewpoint.visible(config.width, config.height, creature.physics.x, creature.physics.y)
+      if (!skip) creature.process(creature.sense(config.sensors, positions, sight))
+      creature.physics.energy -= Creature.metabolicCost(creature.neuron.parameterCount, creature.intention, metabolism, config.neuralCost, config.courtshipCost)
     }
+    creatures.filter(c => c.maxAge <= c.age).seq.foreach(creatures -= _)
+    if ((frame - 1) % Coefficient.speciationInterval == 0) regroup()
+    history.record(StatsSample(frame, time, PopulationStats.of(creatures.seq)))
+    autosave.foreach(a => attempt("Autosaving to " + a.dir)(a.update(this)))
   }
   def render(gc: GameContainer, g: Graphics): Unit = {
     if (terminate) {
       gc.exit()
     } else {
+      g.setBackground(new Color(0.0F, 0.05F * daylight, 0.15F * daylight))
       g.scale(viewpoint.zoom, viewpoint.zoom)
-      creatures.toArray.foreach(_.render(gc, g))
+      g.translate(-viewpoint.x, -viewpoint.y)
+      Heatmap.render(gc, g, this)
+      val swapColor = g.getColor
+      g.setColor(Color.gray)
+      obstacles.foreach(obstacle => g.fill(obstacle.shape))
+      g.setColor(swapColor)
+      creatures.toArray.foreach(c => c.render(gc, g, viewpoint, species.get(c.id)))
+      creatures.find(c => viewpoint.selected.contains(c.id)).foreach { c =>
+        g.setColor(Color.white)
+        g.draw(new geom.Circle(c.physics.x, c.physics.y, c.circle.getRadius + 3.0F))
+        g.setColor(swapColor)
+      }
+      Spread.render(gc, g, this)
+      g.resetTransform()
       Notification.render(gc, g, this)
+      Graph.render(gc, g, this)
+      Minimap.render(gc, g, this)
+      if (captureRequested) {
+        captureRequested = false
+        attempt("Capturing a screenshot")(Screenshot.capture(gc, g, Geneuron.screenshotDir))
+      }
     }
   }
 }
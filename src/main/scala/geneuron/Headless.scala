@@ -0,0 +1,21 @@
+package geneuron
+
+import geneuron.info.{Notification, PopulationStats}
+
+/**
+ * Runs the simulation without a display: `runMain geneuron.Headless [steps] [genome] [stats.csv]`;
+ * `-Dgeneuron.autosave=dir` keeps rotating snapshots of long runs in dir,
+ * and `-Dgeneuron.<field>=value` overrides any WorldConfig field
+ */
+object Headless {
+  def main(args: Array[String]): Unit = {
+    val steps = args.headOption.fold(1000)(_.toInt)
+    val geneuron = new Geneuron("Geneuron", args.lift(1), sys.props.get("geneuron.seed").map(_.toLong), WorldConfig.fromProperties())
+    geneuron.populate()
+    sys.props.get("geneuron.autosave").foreach(dir => geneuron.enableAutosave(dir))
+    (1 to steps).foreach(_ => geneuron.step())
+    args.lift(2).foreach(geneuron.exportStatsCsv)
+    println("Steps: " + geneuron.frame)
+    Notification.lines(PopulationStats.of(geneuron.creatures.seq)).foreach(println)
+  }
+}
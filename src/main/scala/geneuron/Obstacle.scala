@@ -0,0 +1,52 @@
+package geneuron
+
+import geneuron.creature.Physics
+import org.newdawn.slick.geom.{Circle, Rectangle, Shape}
+
+sealed trait Obstacle {
+  def shape: Shape
+
+  /** Nearest point of the obstacle to (x, y), and whether (x, y) is inside. */
+  def nearest(x: Float, y: Float): (Float, Float, Boolean)
+
+  /** Pushes a body of the given radius out of the obstacle and cancels its velocity into it. */
+  def collide(physics: Physics, radius: Float): Unit = {
+    val (qx, qy, inside) = nearest(physics.x, physics.y)
+    val (dx, dy) = (physics.x - qx, physics.y - qy)
+    val d = math.hypot(dx, dy).toFloat
+    if (inside || d < radius) {
+      val (nx, ny) = if (d == 0.0F) (0.0F, -1.0F) else if (inside) (-dx / d, -dy / d) else (dx / d, dy / d)
+      physics.x = qx + nx * radius
+      physics.y = qy + ny * radius
+      val vn = physics.vx * nx + physics.vy * ny
+      if (vn < 0.0F) {
+        physics.vx -= nx * vn
+        physics.vy -= ny * vn
+      }
+    }
+  }
+}
+
+case class RoundObstacle(x: Float, y: Float, radius: Float) extends Obstacle {
+  val shape: Shape = new Circle(x, y, radius)
+  def nearest(px: Float, py: Float): (Float, Float, Boolean) = {
+    val (dx, dy) = (px - x, py - y)
+    val d = math.hypot(dx, dy).toFloat
+    if (d == 0.0F) (x, y - radius, true)
+    else (x + dx / d * radius, y + dy / d * radius, d < radius)
+  }
+}
+
+case class RectObstacle(x: Float, y: Float, width: Float, height: Float) extends Obstacle {
+  val shape: Shape = new Rectangle(x, y, width, height)
+  def nearest(px: Float, py: Float): (Float, Float, Boolean) = {
+    val inside = x < px && px < x + width && y < py && py < y + height
+    if (!inside) {
+      (math.max(x, math.min(x + width, px)), math.max(y, math.min(y + height, py)), false)
+    } else {
+      val edges = Seq((x, py, px - x), (x + width, py, x + width - px), (px, y, py - y), (px, y + height, y + height - py))
+      val (ex, ey, _) = edges.minBy(_._3)
+      (ex, ey, true)
+    }
+  }
+}
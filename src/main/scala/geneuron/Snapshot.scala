@@ -0,0 +1,170 @@
+package geneuron
+
+import java.io.{ByteArrayInputStream, ByteArrayOutputStream, InputStream, InvalidClassException, ObjectInputStream, ObjectOutputStream, ObjectStreamClass}
+import java.nio.charset.StandardCharsets
+import java.nio.file.{Files, Paths, StandardCopyOption}
+import java.util.Base64
+
+import geneuron.creature.{Creature, Physics}
+import org.json4s._
+import org.json4s.native.JsonMethods._
+
+import scala.util.Random
+
+/**
+ * Whole-world save and load, including RNG states so a loaded world continues exactly as the saved one would have;
+ * saves are written to a temporary file and renamed so an interrupted save never corrupts the previous one
+ */
+object Snapshot {
+  def save(path: String, geneuron: Geneuron): Unit = {
+    val json = compact(render(JObject(
+      "frame" -> JInt(geneuron.frame),
+      "lastId" -> JInt(geneuron.lastId),
+      "elapsed" -> JDouble(geneuron.elapsed),
+      "time" -> JDouble(geneuron.time),
+      "random" -> JString(encode(geneuron.random)),
+      "creatures" -> JArray(geneuron.creatures.seq.toList.map(writeCreature)),
+      "obstacles" -> JArray(geneuron.obstacles.map(writeObstacle))
+    )))
+    val target = Paths.get(path).toAbsolutePath
+    val temp = Files.createTempFile(target.getParent, target.getFileName.toString, ".tmp")
+    try {
+      Files.write(temp, json.getBytes(StandardCharsets.UTF_8))
+      Files.move(temp, target, StandardCopyOption.REPLACE_EXISTING, StandardCopyOption.ATOMIC_MOVE)
+    } finally Files.deleteIfExists(temp)
+  }
+
+  def load(path: String, geneuron: Geneuron): Unit = {
+    val ast = parse(new String(Files.readAllBytes(Paths.get(path)), StandardCharsets.UTF_8))
+    val creatures = ast \ "creatures" match {
+      case JArray(xs) => xs.map(v => readCreature(v, geneuron.config))
+      case v => throw new IllegalArgumentException("Requirement: creatures array expected but " + v)
+    }
+    val obstacles = ast \ "obstacles" match {
+      case JArray(xs) => xs.map(readObstacle)
+      case JNothing => Nil
+      case v => throw new IllegalArgumentException("Requirement: obstacles array expected but " + v)
+    }
+    val frame = number(ast, "frame").toLong
+    val lastId = number(ast, "lastId").toLong
+    val elapsed = number(ast, "elapsed").toFloat
+    val time = number(ast, "time").toFloat
+    val random = decode(string(ast, "random"))
+    geneuron.frame = frame
+    geneuron.lastId = lastId
+    geneuron.obstacles = obstacles
+    geneuron.elapsed = elapsed
+    geneuron.time = time
+    geneuron.random = random
+    geneuron.creatures.clear()
+    geneuron.creatures ++= creatures
+    geneuron.regroup()
+    geneuron.history.clear()
+    geneuron.pendingSteps = 0.0F
+    geneuron.autosave.foreach(_.rebase(time))
+  }
+
+  private def writeCreature(c: Creature): JValue = {
+    def floats(xs: Float*) = JArray(xs.map(x => JDouble(x): JValue).toList)
+    JObject(
+      "physics" -> floats(c.physics.x, c.physics.y, c.physics.vx, c.physics.vy, c.physics.deg, c.physics.energy, c.physics.distance),
+      "gene" -> JArray(c.gene.map(d => JDouble(d): JValue).toList),
+      "random" -> JString(encode(c.random)),
+      "id" -> JInt(c.id),
+      "parents" -> JArray(List(c.parents._1, c.parents._2).map(_.fold[JValue](JNull)(JInt(_)))),
+      "mutationRate" -> JDouble(c.mutationRate),
+      "age" -> JInt(c.age),
+      "maxAge" -> JInt(c.maxAge),
+      "sensePhase" -> JInt(c.sensePhase),
+      "decisions" -> floats(c.viewingAngle, c.leftProp, c.rightProp),
+      "intention" -> JBool(c.intention),
+      "color" -> floats(c.color.r, c.color.g, c.color.b),
+      "displayColor" -> floats(c.displayColor.r, c.displayColor.g, c.displayColor.b)
+    )
+  }
+
+  private def readCreature(v: JValue, config: WorldConfig): Creature = {
+    val Seq(x, y, vx, vy, deg, energy, distance) = floats(v, "physics")
+    val physics = new Physics(x, y, vx, vy, deg, energy)
+    physics.distance = distance
+    val gene = (v \ "gene" match {
+      case JArray(xs) => xs.map(number)
+      case g => throw new IllegalArgumentException("Requirement: gene array expected but " + g)
+    }).toArray
+    val parents = v \ "parents" match {
+      case JArray(List(a, b)) => (parent(a), parent(b))
+      case p => throw new IllegalArgumentException("Requirement: parents pair expected but " + p)
+    }
+    val c = new Creature(physics, gene, decode(string(v, "random")), config)(number(v, "id").toLong, parents, number(v, "mutationRate"), integer(v, "maxAge"), number(v, "sensePhase").toInt)
+    val Seq(viewingAngle, leftProp, rightProp) = floats(v, "decisions")
+    c.viewingAngle = viewingAngle
+    c.leftProp = leftProp
+    c.rightProp = rightProp
+    c.intention = v \ "intention" == JBool(true)
+    c.age = integer(v, "age")
+    val Seq(r, g, b) = floats(v, "color")
+    val Seq(dr, dg, db) = floats(v, "displayColor")
+    c.color.r = r; c.color.g = g; c.color.b = b
+    c.displayColor.r = dr; c.displayColor.g = dg; c.displayColor.b = db
+    c
+  }
+
+  private def writeObstacle(o: Obstacle): JValue = o match {
+    case RoundObstacle(x, y, radius) => JObject("round" -> JArray(List(JDouble(x), JDouble(y), JDouble(radius))))
+    case RectObstacle(x, y, width, height) => JObject("rect" -> JArray(List(JDouble(x), JDouble(y), JDouble(width), JDouble(height))))
+  }
+
+  private def readObstacle(v: JValue): Obstacle = v match {
+    case JObject(List(("round", _))) =>
+      val Seq(x, y, radius) = floats(v, "round")
+      RoundObstacle(x, y, radius)
+    case JObject(List(("rect", _))) =>
+      val Seq(x, y, width, height) = floats(v, "rect")
+      RectObstacle(x, y, width, height)
+    case _ => throw new IllegalArgumentException("Requirement: obstacle expected but " + v)
+  }
+
+  private def parent(v: JValue): Option[Long] = v match {
+    case JNull => None
+    case id => Some(number(id).toLong)
+  }
+
+  private def number(v: JValue): Double = v match {
+    case JDouble(d) => d
+    case JInt(i) => i.toDouble
+    case _ => throw new IllegalArgumentException("Requirement: number expected but " + v)
+  }
+  private def number(v: JValue, key: String): Double = number(v \ key)
+  private def integer(v: JValue, key: String): Long = v \ key match {
+    case JInt(i) => i.toLong
+    case x => throw new IllegalArgumentException(s"Requirement: $key integer expected but " + x)
+  }
+  private def floats(v: JValue, key: String): Seq[Float] = v \ key match {
+    case JArray(xs) => xs.map(number(_).toFloat)
+    case x => throw new IllegalArgumentException(s"Requirement: $key array expected but " + x)
+  }
+  private def string(v: JValue, key: String): String = v \ key match {
+    case JString(s) => s
+    case x => throw new IllegalArgumentException(s"Requirement: $key string expected but " + x)
+  }
+
+  private def encode(random: Random): String = {
+    val bytes = new ByteArrayOutputStream
+    val out = new ObjectOutputStream(bytes)
+    out.writeObject(random.self)
+    out.close()
+    Base64.getEncoder.encodeToString(bytes.toByteArray)
+  }
+  private def decode(s: String): Random = {
+    val in = new RandomInputStream(new ByteArrayInputStream(Base64.getDecoder.decode(s)))
+    try new Random(in.readObject().asInstanceOf[java.util.Random]) finally in.close()
+  }
+
+  /** Refuses every class but java.util.Random, so a crafted snapshot cannot instantiate anything else. */
+  private class RandomInputStream(in: InputStream) extends ObjectInputStream(in) {
+    override protected def resolveClass(desc: ObjectStreamClass): Class[_] = {
+      if (desc.getName != classOf[java.util.Random].getName) throw new InvalidClassException(desc.getName, "Requirement: only java.util.Random in a snapshot")
+      super.resolveClass(desc)
+    }
+  }
+}
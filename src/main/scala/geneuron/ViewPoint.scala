@@ -1,5 +1,105 @@
 package geneuron
 
+import geneuron.info.{Heatmap, Notification}
+import org.newdawn.slick.GameContainer
+
+object ViewPoint {
+  sealed trait ColorMode
+  case object Body extends ColorMode
+  case object Energy extends ColorMode
+  case object Species extends ColorMode
+  case object Age extends ColorMode
+  val colorModes: Seq[ColorMode] = Seq(Body, Energy, Species, Age)
+
+  case class Keyframe(time: Float, x: Float, y: Float, zoom: Float)
+
+  /** Zoom and position linearly interpolated along a time-ordered path, held at either end. */
+  def interpolate(path: Seq[Keyframe], time: Float): (Float, Float, Float) = {
+    val (before, after) = path.span(_.time <= time)
+    (before.lastOption, after.headOption) match {
+      case (Some(a), Some(b)) =>
+        val t = (time - a.time) / (b.time - a.time)
+        (a.zoom + (b.zoom - a.zoom) * t, a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+      case (Some(a), None) => (a.zoom, a.x, a.y)
+      case (None, Some(b)) => (b.zoom, b.x, b.y)
+      case (None, None) => throw new IllegalArgumentException("Requirement: camera path must have keyframes")
+    }
+  }
+
+  /** Zoom and top-left corner that frame every position, with a margin, inside a width x height screen. */
+  def fit(width: Float, height: Float, positions: Seq[(Float, Float)]): (Float, Float, Float) = {
+    val (xs, ys) = positions.unzip
+    val (left, right, top, bottom) = (xs.min, xs.max, ys.min, ys.max)
+    val spanX = math.max(right - left, 1.0F) + Coefficient.autoZoomMargin * 2
+    val spanY = math.max(bottom - top, 1.0F) + Coefficient.autoZoomMargin * 2
+    val zoom = math.max(Coefficient.minZoom, math.min(Coefficient.maxZoom, math.min(width / spanX, height / spanY)))
+    (zoom, (left + right) / 2 - width / zoom / 2, (top + bottom) / 2 - height / zoom / 2)
+  }
+}
+
 class ViewPoint {
   var zoom = 1.0F
+  var x = 0.0F
+  var y = 0.0F
+  var autoZoom = false
+  var uiScale = 1.0F
+  var cameraPath: Seq[ViewPoint.Keyframe] = Nil
+  var selected: Option[Long] = None
+  var smoothColor = true
+  var colorMode: ViewPoint.ColorMode = ViewPoint.Body
+  var lod = false
+  var energyBar = true
+  var spread = false
+  var graph = false
+  var minimap = false
+  var heatmap: Option[Heatmap.Source] = None
+  var hud = true
+  var statsPanel = true
+  var statsCorner: Notification.Corner = Notification.TopLeft
+  def nextStatsCorner(): Unit = {
+    statsCorner = Notification.corners((Notification.corners.indexOf(statsCorner) + 1) % Notification.corners.length)
+  }
+  /** Moves the camera so the world point (wx, wy) is at the center of a width x height screen. */
+  def center(width: Int, height: Int, wx: Float, wy: Float): Unit = {
+    x = wx - width / zoom / 2
+    y = wy - height / zoom / 2
+  }
+  def toWorld(sx: Float, sy: Float): (Float, Float) = (x + sx / zoom, y + sy / zoom)
+  /** Changes zoom while keeping the world point under the screen position (cx, cy) fixed. */
+  def zoomAt(newZoom: Float, cx: Float, cy: Float): Unit = {
+    val (wx, wy) = (x + cx / zoom, y + cy / zoom)
+    zoom = math.max(Coefficient.minZoom, math.min(Coefficient.maxZoom, newZoom))
+    x = wx - cx / zoom
+    y = wy - cy / zoom
+  }
+  def setColorMode(mode: ViewPoint.ColorMode): Unit = {
+    colorMode = mode
+  }
+  def nextColorMode(): Unit = {
+    setColorMode(ViewPoint.colorModes((ViewPoint.colorModes.indexOf(colorMode) + 1) % ViewPoint.colorModes.length))
+  }
+  def setHeatmap(source: Option[Heatmap.Source]): Unit = {
+    heatmap = source
+  }
+  def setCameraPath(keyframes: Seq[ViewPoint.Keyframe]): Unit = {
+    cameraPath = keyframes.sortBy(_.time)
+  }
+  def tour(time: Float): Unit = {
+    val (z, px, py) = ViewPoint.interpolate(cameraPath, time)
+    zoom = z
+    x = px
+    y = py
+  }
+  def frame(gc: GameContainer, positions: Seq[(Float, Float)]): Unit = {
+    if (positions.nonEmpty) {
+      val (z, fx, fy) = ViewPoint.fit(gc.getWidth, gc.getHeight, positions)
+      zoom = z
+      x = fx
+      y = fy
+    }
+  }
+  def visible(width: Int, height: Int, x: Float, y: Float): Boolean = {
+    val (sx, sy) = ((x - this.x) * zoom, (y - this.y) * zoom)
+    0F <= sx && sx <= width && 0F <= sy && sy <= height
+  }
 }
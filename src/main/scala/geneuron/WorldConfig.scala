@@ -0,0 +1,85 @@
+package geneuron
+
+import geneuron.creature.SensorConfig
+import geneuron.neuron.{Crossover, Mutation}
+
+import scala.util.Try
+
+/**
+ * lifespan: Steps a creature lives before dying of old age; unlimited by default as nothing replaces the dead yet
+ * dayLength: Steps in one day/night cycle
+ * nightMetabolism: Extra fraction of energy cost spent at midnight
+ * nightBlindness: Fraction of sight lost at midnight
+ * senseInterval: Steps between a creature's decisions, staggered across creatures
+ * courtshipCost: Energy per step spent while intending to reproduce
+ * neuralCost: Energy per step spent per network parameter
+ * spawnDistance: Minimum distance between initial creatures, kept on a best-effort basis
+ * speciesThreshold: Genome distance within which creatures belong to one species
+ * stepRate: Simulation steps per simulated second, independent of rendering
+ * renderFps: Frame rate cap of the window; each frame runs stepRate / renderFps steps at normal speed
+ */
+case class WorldConfig(
+  width: Int = 640,
+  height: Int = 480,
+  initialPopulation: Int = 10,
+  lifespan: Long = Long.MaxValue,
+  dayLength: Long = 3600L,
+  nightMetabolism: Float = 0.0F,
+  nightBlindness: Float = 0.0F,
+  senseInterval: Int = 1,
+  courtshipCost: Float = 0.0F,
+  neuralCost: Float = 0.0F,
+  crossover: Crossover = Crossover.Uniform,
+  mutation: Mutation = Mutation.Uniform,
+  sensors: SensorConfig = SensorConfig(),
+  spawnDistance: Float = 30.0F,
+  speciesThreshold: Double = 10.0,
+  stepRate: Int = 60,
+  renderFps: Int = 60
+) {
+  if (senseInterval < 1) throw new IllegalArgumentException("Requirement: senseInterval is at least 1")
+  if (stepRate < 1 || renderFps < 1) throw new IllegalArgumentException("Requirement: stepRate and renderFps are at least 1")
+  /** Simulated seconds per step. */
+  def stepTime: Float = 1.0F / stepRate
+  def stepsPerFrame: Float = stepRate.toFloat / renderFps
+}
+
+object WorldConfig {
+  /** Defaults overridden by `geneuron.<field>` properties, e.g. `-Dgeneuron.senseInterval=3` or `-Dgeneuron.sensors.vision=8`. */
+  def fromProperties(props: collection.Map[String, String] = sys.props): WorldConfig = {
+    def get[T](key: String, default: T)(parse: String => Option[T]): T = props.get("geneuron." + key).fold(default) { value =>
+      parse(value).getOrElse(throw new IllegalArgumentException(s"Requirement: valid geneuron.$key but $value"))
+    }
+    def int(key: String, default: Int) = get(key, default)(v => Try(v.toInt).toOption)
+    def long(key: String, default: Long) = get(key, default)(v => Try(v.toLong).toOption)
+    def float(key: String, default: Float) = get(key, default)(v => Try(v.toFloat).toOption)
+    def double(key: String, default: Double) = get(key, default)(v => Try(v.toDouble).toOption)
+    def boolean(key: String, default: Boolean) = get(key, default)(v => Try(v.toBoolean).toOption)
+    val d = WorldConfig()
+    WorldConfig(
+      width = int("width", d.width),
+      height = int("height", d.height),
+      initialPopulation = int("initialPopulation", d.initialPopulation),
+      lifespan = long("lifespan", d.lifespan),
+      dayLength = long("dayLength", d.dayLength),
+      nightMetabolism = float("nightMetabolism", d.nightMetabolism),
+      nightBlindness = float("nightBlindness", d.nightBlindness),
+      senseInterval = int("senseInterval", d.senseInterval),
+      courtshipCost = float("courtshipCost", d.courtshipCost),
+      neuralCost = float("neuralCost", d.neuralCost),
+      crossover = get("crossover", d.crossover)(Crossover.fromName),
+      mutation = get("mutation", d.mutation)(Mutation.fromName),
+      sensors = SensorConfig(
+        speed = boolean("sensors.speed", d.sensors.speed),
+        heading = boolean("sensors.heading", d.sensors.heading),
+        energy = boolean("sensors.energy", d.sensors.energy),
+        vision = int("sensors.vision", d.sensors.vision),
+        noise = int("sensors.noise", d.sensors.noise)
+      ),
+      spawnDistance = float("spawnDistance", d.spawnDistance),
+      speciesThreshold = double("speciesThreshold", d.speciesThreshold),
+      stepRate = int("stepRate", d.stepRate),
+      renderFps = int("renderFps", d.renderFps)
+    )
+  }
+}
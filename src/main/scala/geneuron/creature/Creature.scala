@@ -1,13 +1,40 @@
 package geneuron.creature
 
-import geneuron.Coefficient
+import geneuron.{Coefficient, ViewPoint, WorldConfig}
 import geneuron.neuron.{NeuronCluster, Neuron}
 import org.newdawn.slick.geom.Circle
 import org.newdawn.slick.{Color, GameContainer, Graphics}
 import scala.util.Random
 
 object Creature {
-  val neuronLayers = Seq(29, 30, 7)
+  def neuronLayers(sensors: SensorConfig): Seq[Int] = Seq(sensors.length, 30, 7)
+  def geneLength(sensors: SensorConfig): Int = NeuronCluster.parameterCount(neuronLayers(sensors))
+  def randomGene(random: Random, sensors: SensorConfig = SensorConfig()): Array[Double] = Array.fill(geneLength(sensors))(random.nextDouble())
+  def lerp(from: Color, to: Color, t: Float): Unit = {
+    from.r += (to.r - from.r) * t
+    from.g += (to.g - from.g) * t
+    from.b += (to.b - from.b) * t
+  }
+  def energyFraction(energy: Float): Float = math.max(0.0F, math.min(1.0F, energy / Coefficient.maxEnergy))
+  def energyBarWidth(energy: Float): Float = energyFraction(energy) * Coefficient.energyBarWidth
+  def speciesColor(species: Int): Color = new Color(java.awt.Color.HSBtoRGB((species * 0.618034F) % 1.0F, 0.8F, 1.0F))
+  /** Energy spent per step on running a brain of the given size and, while intending to reproduce, on courtship. */
+  def metabolicCost(parameters: Int, intention: Boolean, metabolism: Float, neural: Float, courtship: Float): Float = {
+    (neural * parameters + (if (intention) courtship else 0.0F)) * metabolism
+  }
+  def energyColor(energy: Float): Color = {
+    val t = energyFraction(energy)
+    new Color(1.0F - t, t, 0.0F)
+  }
+  /** Fraction of the lifespan lived, against the expected lifespan when maxAge is unlimited. */
+  def ageFraction(age: Long, maxAge: Long): Float = {
+    val span = if (maxAge == Long.MaxValue) Coefficient.expectedLifespan else maxAge
+    math.min(1.0F, age.toFloat / span)
+  }
+  def ageColor(age: Long, maxAge: Long): Color = {
+    val t = ageFraction(age, maxAge)
+    new Color(t, 1.0F - t, 1.0F - t)
+  }
 }
 
 /**
@@ -20,16 +47,63 @@ object Creature {
  * 5: Body color B
  * 6: Intention(Reproduce:True, Eat:False)
  */
-class Creature(val physics: Physics, val gene: Array[Double] = Array.fill(30 * 30 + 31 * 7)(Random.nextDouble())) {
+class Creature(val physics: Physics, val gene: Array[Double] = Creature.randomGene(Random), val random: Random = new Random, val config: WorldConfig = WorldConfig())(val id: Long = 0L, val parents: (Option[Long], Option[Long]) = (None, None), val mutationRate: Double = Coefficient.mutationRate, val maxAge: Long = Long.MaxValue, val sensePhase: Int = random.nextInt(config.senseInterval)) {
 
-  val neuron = new NeuronCluster(Creature.neuronLayers, gene)
+  val neuron = new NeuronCluster(Creature.neuronLayers(config.sensors), gene)
   var viewingAngle: Float = 0.0F
   var leftProp: Float = 0.0F
   var rightProp: Float = 0.0F
   var intention: Boolean = false
+  var age: Long = 0L
 
   val circle: Circle = new Circle(physics.x, physics.y, 10.0F)
   val color: Color = new Color(1F, 1F, 1F)
+  val displayColor: Color = new Color(color)
+
+  /** Own speed normalized to [0, 1] and heading as (sin, cos), continuous across 0/360 degrees. */
+  def proprioception: Array[Float] = {
+    val speed = math.hypot(physics.vx, physics.vy).toFloat / Coefficient.maxSpeed
+    val rad = math.toRadians(physics.deg)
+    Array(math.min(1.0F, speed), math.sin(rad).toFloat, math.cos(rad).toFloat)
+  }
+
+  /**
+   * Per sector around the heading, closeness of the nearest other creature within sight:
+   * 1 when touching, 0 when at sight distance or none
+   */
+  def vision(sectors: Int, others: Seq[(Long, Float, Float)], sight: Float = Coefficient.sight): Seq[Float] = {
+    val closeness = Array.fill(sectors)(0.0F)
+    others.foreach { case (otherId, x, y) =>
+      val (dx, dy) = (x - physics.x, y - physics.y)
+      val d = math.hypot(dx, dy).toFloat
+      if (otherId != id && d < sight) {
+        val angle = ((math.toDegrees(math.atan2(dy, dx)) - physics.deg) % 360 + 360) % 360
+        val sector = math.min(sectors - 1, (angle / 360 * sectors).toInt)
+        closeness(sector) = math.max(closeness(sector), 1.0F - d / sight)
+      }
+    }
+    closeness
+  }
+
+  def sense(sensors: SensorConfig, others: Seq[(Long, Float, Float)] = Nil, sight: Float = Coefficient.sight): Array[Float] = {
+    val Array(speed, sin, cos) = proprioception
+    val in = Seq(
+      sensors.speed -> Seq(speed),
+      sensors.heading -> Seq(sin, cos),
+      sensors.energy -> Seq(physics.energy)
+    ).collect { case (true, values) => values }.flatten ++ (if (0 < sensors.vision) vision(sensors.vision, others, sight) else Nil)
+    (in ++ Seq.fill(sensors.noise)(random.nextFloat())).toArray
+  }
+
+  /** Whether this creature senses and decides on the given frame, staggered across creatures by sensePhase. */
+  def senseDue(frame: Long, interval: Int): Boolean = (frame + sensePhase) % interval == 0
+
+  def crossover(partner: Creature, physics: Physics, childId: Long): Creature = {
+    val rate = (mutationRate + partner.mutationRate) / 2 + random.nextGaussian() * Coefficient.mutationRateSigma
+    val childRate = math.max(Coefficient.minMutationRate, math.min(Coefficient.maxMutationRate, rate))
+    val childGene = config.mutation(config.crossover(gene, partner.gene, random), childRate, random)
+    new Creature(physics, childGene, new Random(random.nextLong()), config)(childId, (Some(id), Some(partner.id)), childRate, maxAge / 2 + partner.maxAge / 2)
+  }
 
   def process(in: Array[Float]) = {
     val out = neuron.process(in.map(_.toDouble)).map(_.toFloat)
@@ -42,7 +116,7 @@ class Creature(val physics: Physics, val gene: Array[Double] = Array.fill(30 * 3
     intention = 0.5F < out(6)
   }
 
-  def render(gc: GameContainer, g: Graphics) = {
+  def render(gc: GameContainer, g: Graphics, viewpoint: ViewPoint, species: Option[Int] = None) = {
     /* Swap Context */
     val swapColor = g.getColor
     g.pushTransform()
@@ -50,15 +124,33 @@ class Creature(val physics: Physics, val gene: Array[Double] = Array.fill(30 * 3
     /* Circle */
     circle.setCenterX(physics.x)
     circle.setCenterY(physics.y)
-    g.setColor(color)
+    val target = viewpoint.colorMode match {
+      case ViewPoint.Body => color
+      case ViewPoint.Energy => Creature.energyColor(physics.energy)
+      case ViewPoint.Species => species.fold(Color.gray)(Creature.speciesColor)
+      case ViewPoint.Age => Creature.ageColor(age, maxAge)
+    }
+    Creature.lerp(displayColor, target, if (viewpoint.smoothColor) Coefficient.colorLerp else 1.0F)
+    g.setColor(displayColor)
     g.fill(circle)
 
+    /* Energy bar */
+    if (viewpoint.energyBar && Coefficient.energyBarZoom <= viewpoint.zoom) {
+      val (left, top) = (physics.x - Coefficient.energyBarWidth / 2, physics.y - circle.getRadius - 6F)
+      g.setColor(Color.darkGray)
+      g.fillRect(left, top, Coefficient.energyBarWidth, 3F)
+      g.setColor(Color.green)
+      g.fillRect(left, top, Creature.energyBarWidth(physics.energy), 3F)
+    }
+
     /* Line */
     g.setColor(Color.pink)
+    g.setLineWidth(viewpoint.uiScale)
     g.translate(physics.x, physics.y)
     g.rotate(0F, 0F, physics.deg)
     g.drawLine(0F, 0F, Coefficient.sight, Coefficient.sight)
     g.resetTransform()
+    g.resetLineWidth()
 
     /* Reset to Context */
     g.setColor(swapColor)
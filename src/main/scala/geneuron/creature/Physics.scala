@@ -2,8 +2,10 @@ package geneuron.creature
 
 class Physics(_x:Float, _y:Float, _vx:Float, _vy:Float, _deg:Float, _energy:Float) {
   var (x, y, vx, vy, deg, energy) = (_x, _y, _vx, _vy, _deg, _energy)
+  var distance:Float = 0.0F
   def update():Unit = {
     x += vx
     y += vy
+    distance += math.sqrt(vx * vx + vy * vy).toFloat
   }
 }
@@ -0,0 +1,13 @@
+package geneuron.creature
+
+/**
+ * Senses fed to the neuron cluster, in input order
+ * speed: Own normalized speed
+ * heading: Own heading as (sin, cos)
+ * energy: Own energy
+ * vision: Count of angular sectors around the heading, each sensing its nearest creature
+ * noise: Count of random inputs
+ */
+case class SensorConfig(speed: Boolean = true, heading: Boolean = true, energy: Boolean = false, vision: Int = 0, noise: Int = 26) {
+  def length: Int = Seq(speed, heading, heading, energy).count(identity) + vision + noise
+}
@@ -0,0 +1,24 @@
+package geneuron.info
+
+import geneuron.{Coefficient, Geneuron}
+import org.newdawn.slick.{Color, GameContainer, Graphics}
+
+object Graph {
+  def render(gc: GameContainer, g: Graphics, geneuron: Geneuron): Unit = {
+    val samples = if (geneuron.viewpoint.hud && geneuron.viewpoint.graph) geneuron.history.latest(Coefficient.graphSamples) else Nil
+    if (1 < samples.length) {
+      val swapColor = g.getColor
+      val (w, h) = (Coefficient.graphWidth * geneuron.viewpoint.uiScale, Coefficient.graphHeight * geneuron.viewpoint.uiScale)
+      val (left, top) = (gc.getWidth - w - 10, gc.getHeight - h - 10)
+      val max = math.max(1, samples.map(_.stats.population).max)
+      val points = samples.zipWithIndex.map { case (sample, i) =>
+        (left + w * i / (samples.length - 1), top + h - h * sample.stats.population / max)
+      }
+      g.setColor(Color.darkGray)
+      g.drawRect(left, top, w, h)
+      g.setColor(Color.green)
+      (points zip points.tail).foreach { case ((x1, y1), (x2, y2)) => g.drawLine(x1, y1, x2, y2) }
+      g.setColor(swapColor)
+    }
+  }
+}
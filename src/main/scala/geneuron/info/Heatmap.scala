@@ -0,0 +1,37 @@
+package geneuron.info
+
+import geneuron.{Coefficient, Geneuron}
+import org.newdawn.slick.{Color, GameContainer, Graphics}
+
+object Heatmap {
+  sealed trait Source
+  case object Creatures extends Source
+
+  /** Counts of positions per cell of a bins x bins grid over width x height; positions outside are ignored. */
+  def bin(width: Float, height: Float, bins: Int, positions: Seq[(Float, Float)]): Array[Array[Int]] = {
+    val counts = Array.fill(bins, bins)(0)
+    positions.foreach { case (x, y) =>
+      val (i, j) = ((x / width * bins).toInt, (y / height * bins).toInt)
+      if (0F <= x && 0F <= y && i < bins && j < bins) counts(i)(j) += 1
+    }
+    counts
+  }
+
+  def render(gc: GameContainer, g: Graphics, geneuron: Geneuron): Unit = {
+    geneuron.viewpoint.heatmap.foreach { source =>
+      val positions = source match {
+        case Creatures => geneuron.creatures.seq.toSeq.map(c => (c.physics.x, c.physics.y))
+      }
+      val (width, height, bins) = (geneuron.config.width.toFloat, geneuron.config.height.toFloat, Coefficient.heatmapBins)
+      val counts = bin(width, height, bins, positions)
+      val max = math.max(1, counts.map(_.max).max)
+      val swapColor = g.getColor
+      for (i <- 0 until bins; j <- 0 until bins if 0 < counts(i)(j)) {
+        val t = counts(i)(j).toFloat / max
+        g.setColor(new Color(t, 0.0F, 1.0F - t, 0.4F))
+        g.fillRect(i * width / bins, j * height / bins, width / bins, height / bins)
+      }
+      g.setColor(swapColor)
+    }
+  }
+}
@@ -0,0 +1,44 @@
+package geneuron.info
+
+import geneuron.{Coefficient, Geneuron}
+import org.newdawn.slick.{Color, GameContainer, Graphics}
+
+object Minimap {
+  /** Screen rectangle (left, top, width, height) of the minimap, keeping the world's aspect ratio. */
+  def bounds(gc: GameContainer, geneuron: Geneuron): (Float, Float, Float, Float) = {
+    val w = Coefficient.minimapWidth * geneuron.viewpoint.uiScale
+    val h = w * geneuron.config.height / geneuron.config.width
+    (10.0F, gc.getHeight - h - 10.0F, w, h)
+  }
+
+  /** World position under the screen position (sx, sy), if it is inside the minimap. */
+  def toWorld(gc: GameContainer, geneuron: Geneuron, sx: Float, sy: Float): Option[(Float, Float)] = {
+    val (left, top, w, h) = bounds(gc, geneuron)
+    if (left <= sx && sx <= left + w && top <= sy && sy <= top + h)
+      Some(((sx - left) / w * geneuron.config.width, (sy - top) / h * geneuron.config.height))
+    else None
+  }
+
+  def render(gc: GameContainer, g: Graphics, geneuron: Geneuron): Unit = {
+    if (geneuron.viewpoint.hud && geneuron.viewpoint.minimap) {
+      val swapColor = g.getColor
+      val (left, top, w, h) = bounds(gc, geneuron)
+      val scale = w / geneuron.config.width
+      g.setColor(new Color(0.0F, 0.0F, 0.0F, 0.6F))
+      g.fillRect(left, top, w, h)
+      g.setColor(Color.white)
+      geneuron.creatures.seq.foreach { c =>
+        val (x, y) = (c.physics.x * scale, c.physics.y * scale)
+        if (0F <= x && x <= w && 0F <= y && y <= h) g.fillRect(left + x - 1, top + y - 1, 2, 2)
+      }
+      val viewpoint = geneuron.viewpoint
+      g.setColor(Color.yellow)
+      g.setClip(left.toInt, top.toInt, w.toInt + 1, h.toInt + 1)
+      g.drawRect(left + viewpoint.x * scale, top + viewpoint.y * scale, gc.getWidth / viewpoint.zoom * scale, gc.getHeight / viewpoint.zoom * scale)
+      g.clearClip()
+      g.setColor(Color.gray)
+      g.drawRect(left, top, w, h)
+      g.setColor(swapColor)
+    }
+  }
+}
@@ -1,11 +1,60 @@
 package geneuron.info
 
 import geneuron.Geneuron
-import org.newdawn.slick.{Graphics, GameContainer}
+import org.newdawn.slick.{Color, Font, Graphics, GameContainer}
 
 object Notification {
+  sealed trait Corner
+  case object TopLeft extends Corner
+  case object TopRight extends Corner
+  case object BottomRight extends Corner
+  case object BottomLeft extends Corner
+  val corners: Seq[Corner] = Seq(TopLeft, TopRight, BottomRight, BottomLeft)
+
+  def lines(stats: PopulationStats): Seq[String] = Seq(
+    "Creatures: " + stats.population,
+    "Total energy: %.2f".format(stats.totalEnergy),
+    "Energy: %.2f".format(stats.meanEnergy),
+    "Distance: %.1f".format(stats.meanDistance),
+    "Reproducing: " + stats.reproducing
+  )
+
+  def panelSize(texts: Seq[String], font: Font, scale: Float): (Float, Float) = {
+    (texts.map(font.getWidth).max * scale, font.getLineHeight * texts.length * scale)
+  }
+
+  /** Screen position of a w by h panel in corner; at the top left it sits below the scaled FPS line. */
+  def panelPosition(corner: Corner, w: Float, h: Float, width: Int, height: Int, font: Font, scale: Float): (Float, Float) = corner match {
+    case TopLeft => (10F, 10F + font.getLineHeight * scale)
+    case TopRight => (width - w - 10, 10F)
+    case BottomRight => (width - w - 10, height - h - 10)
+    case BottomLeft => (10F, height - h - 10)
+  }
+
+  /** Draws the HUD text, including the FPS counter Slick would otherwise draw after screenshots are taken. */
   def render(gc: GameContainer, g: Graphics, geneuron: Geneuron): Unit = {
-    gc.setShowFPS(true)
-    g.drawString("Creatures: " + geneuron.creatures.size, 10, 23)
+    if (geneuron.viewpoint.hud) {
+      val scale = geneuron.viewpoint.uiScale
+      val swapColor = g.getColor
+      g.setColor(Color.white)
+      g.pushTransform()
+      g.scale(scale, scale)
+      g.drawString("FPS: " + gc.getFPS, 10F / scale, 10F / scale)
+      g.popTransform()
+      g.setColor(swapColor)
+    }
+    if (geneuron.viewpoint.hud && geneuron.viewpoint.statsPanel) {
+      val texts = lines(PopulationStats.of(geneuron.creatures.seq))
+      val font = g.getFont
+      val scale = geneuron.viewpoint.uiScale
+      val (w, h) = panelSize(texts, font, scale)
+      val (x, y) = panelPosition(geneuron.viewpoint.statsCorner, w, h, gc.getWidth, gc.getHeight, font, scale)
+      g.pushTransform()
+      g.scale(scale, scale)
+      texts.zipWithIndex.foreach { case (text, i) =>
+        g.drawString(text, x / scale, y / scale + i * font.getLineHeight)
+      }
+      g.popTransform()
+    }
   }
 }
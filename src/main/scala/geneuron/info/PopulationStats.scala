@@ -0,0 +1,16 @@
+package geneuron.info
+
+import geneuron.creature.Creature
+
+case class PopulationStats(population: Int, totalEnergy: Float, meanEnergy: Float, meanDistance: Float, reproducing: Int)
+
+object PopulationStats {
+  /** Sums in id order, so equal populations give bit-identical stats whatever the collection's iteration order. */
+  def of(population: Iterable[Creature]): PopulationStats = {
+    val creatures = population.toSeq.sortBy(_.id)
+    val n = creatures.size
+    def total(f: Creature => Float) = creatures.foldLeft(0.0F)((sum, c) => sum + f(c))
+    def mean(f: Creature => Float) = if (n == 0) 0.0F else total(f) / n
+    PopulationStats(n, total(_.physics.energy), mean(_.physics.energy), mean(_.physics.distance), creatures.count(_.intention))
+  }
+}
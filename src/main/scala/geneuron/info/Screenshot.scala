@@ -0,0 +1,21 @@
+package geneuron.info
+
+import java.io.File
+import java.text.SimpleDateFormat
+import java.util.Date
+
+import org.newdawn.slick.imageout.ImageOut
+import org.newdawn.slick.{GameContainer, Graphics, Image}
+
+object Screenshot {
+  /** Writes what has been drawn so far this frame to a timestamped PNG in dir and returns its path. */
+  def capture(gc: GameContainer, g: Graphics, dir: String): String = {
+    new File(dir).mkdirs()
+    val path = new File(dir, "geneuron-" + new SimpleDateFormat("yyyyMMdd-HHmmss-SSS").format(new Date) + ".png").getPath
+    val image = new Image(gc.getWidth, gc.getHeight)
+    g.copyArea(image, 0, 0)
+    ImageOut.write(image, path)
+    image.destroy()
+    path
+  }
+}
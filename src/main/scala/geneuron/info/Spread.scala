@@ -0,0 +1,30 @@
+package geneuron.info
+
+import geneuron.Geneuron
+import org.newdawn.slick.geom.Circle
+import org.newdawn.slick.{Color, GameContainer, Graphics}
+
+object Spread {
+  def centroid(positions: Seq[(Float, Float)]): (Float, Float) = {
+    val (xs, ys) = positions.unzip
+    (xs.sum / positions.length, ys.sum / positions.length)
+  }
+
+  /** Root mean square distance of the positions from their centroid. */
+  def deviation(positions: Seq[(Float, Float)]): Float = {
+    val (cx, cy) = centroid(positions)
+    math.sqrt(positions.map { case (x, y) => (x - cx) * (x - cx) + (y - cy) * (y - cy) }.sum / positions.length).toFloat
+  }
+
+  def render(gc: GameContainer, g: Graphics, geneuron: Geneuron): Unit = {
+    val positions = geneuron.creatures.seq.toSeq.map(c => (c.physics.x, c.physics.y))
+    if (geneuron.viewpoint.spread && positions.nonEmpty) {
+      val swapColor = g.getColor
+      val (cx, cy) = centroid(positions)
+      g.setColor(Color.cyan)
+      g.draw(new Circle(cx, cy, deviation(positions)))
+      g.fill(new Circle(cx, cy, 3.0F))
+      g.setColor(swapColor)
+    }
+  }
+}
@@ -0,0 +1,48 @@
+package geneuron.info
+
+import java.io.{File, FileWriter, PrintWriter}
+
+case class StatsSample(frame: Long, time: Float, stats: PopulationStats)
+
+/** Ring buffer of the newest capacity samples. */
+class StatsHistory(capacity: Int) {
+  private val samples = new Array[StatsSample](capacity)
+  private var start = 0
+  private var size = 0
+  private var exportedFrame = 0L
+  def record(sample: StatsSample): Unit = {
+    samples((start + size) % capacity) = sample
+    if (size < capacity) size += 1 else start = (start + 1) % capacity
+  }
+  def length: Int = size
+  /** The newest n samples, oldest first, reading only those n. */
+  def latest(n: Int): Seq[StatsSample] = {
+    (size - math.min(n, size) until size).map(i => samples((start + i) % capacity))
+  }
+  def toSeq: Seq[StatsSample] = latest(size)
+  /** Drops every sample and the export cursor, as when another world replaces the recorded one. */
+  def clear(): Unit = {
+    samples.indices.foreach(samples(_) = null)
+    start = 0
+    size = 0
+    exportedFrame = 0L
+  }
+
+  /**
+   * Appends a CSV row for every sample recorded since the previous export,
+   * writing the header only when the file is new or empty
+   */
+  def exportCsv(path: String): Unit = {
+    val file = new File(path)
+    val header = !file.exists || file.length == 0
+    val pending = toSeq.filter(exportedFrame < _.frame)
+    val out = new PrintWriter(new FileWriter(file, true))
+    try {
+      if (header) out.println("frame,time,population,total_energy,mean_energy,mean_distance,reproducing")
+      pending.foreach { case StatsSample(frame, time, s) =>
+        out.println(Seq(frame, time, s.population, s.totalEnergy, s.meanEnergy, s.meanDistance, s.reproducing).mkString(","))
+      }
+    } finally out.close()
+    pending.lastOption.foreach(sample => exportedFrame = sample.frame)
+  }
+}
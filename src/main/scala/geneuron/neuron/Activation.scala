@@ -0,0 +1,34 @@
+package geneuron.neuron
+
+sealed trait Activation extends (Double => Double) {
+  def name: String
+}
+
+object Activation {
+  case object Sigmoid extends Activation {
+    val name = "sigmoid"
+    def apply(x: Double): Double = 1.0 / (math.exp(-x) + 1.0)
+  }
+  case object Tanh extends Activation {
+    val name = "tanh"
+    def apply(x: Double): Double = math.tanh(x)
+  }
+  case object ReLU extends Activation {
+    val name = "relu"
+    def apply(x: Double): Double = math.max(0.0, x)
+  }
+  case class LeakyReLU(slope: Double) extends Activation {
+    val name = s"leaky_relu($slope)"
+    def apply(x: Double): Double = if (0.0 < x) x else slope * x
+  }
+
+  private val Leaky = """leaky_relu\((.+)\)""".r
+
+  def fromName(name: String): Option[Activation] = name match {
+    case Sigmoid.name => Some(Sigmoid)
+    case Tanh.name => Some(Tanh)
+    case ReLU.name => Some(ReLU)
+    case Leaky(slope) => scala.util.Try(slope.toDouble).toOption.map(LeakyReLU)
+    case _ => None
+  }
+}
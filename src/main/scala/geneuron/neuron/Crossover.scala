@@ -0,0 +1,39 @@
+package geneuron.neuron
+
+import scala.util.Random
+
+sealed trait Crossover {
+  def name: String
+  def apply(a: Array[Double], b: Array[Double], random: Random = Random): Array[Double]
+}
+
+object Crossover {
+  def singlePoint(a: Array[Double], b: Array[Double], locus: Int): Array[Double] = {
+    a.take(locus) ++ b.drop(locus)
+  }
+  def twoPoint(a: Array[Double], b: Array[Double], from: Int, until: Int): Array[Double] = {
+    a.take(from) ++ b.slice(from, until) ++ a.drop(until)
+  }
+
+  def fromName(name: String): Option[Crossover] = Seq(SinglePoint, TwoPoint, Uniform).find(_.name == name)
+
+  case object SinglePoint extends Crossover {
+    val name = "single_point"
+    override def apply(a: Array[Double], b: Array[Double], random: Random): Array[Double] = {
+      singlePoint(a, b, random.nextInt(a.length + 1))
+    }
+  }
+  case object TwoPoint extends Crossover {
+    val name = "two_point"
+    override def apply(a: Array[Double], b: Array[Double], random: Random): Array[Double] = {
+      val Seq(from, until) = Seq.fill(2)(random.nextInt(a.length + 1)).sorted
+      twoPoint(a, b, from, until)
+    }
+  }
+  case object Uniform extends Crossover {
+    val name = "uniform"
+    override def apply(a: Array[Double], b: Array[Double], random: Random): Array[Double] = {
+      (a zip b).map { case (x, y) => if (random.nextBoolean()) x else y }
+    }
+  }
+}
@@ -0,0 +1,29 @@
+package geneuron.neuron
+
+import java.nio.charset.StandardCharsets
+import java.nio.file.{Files, Paths}
+
+import org.json4s._
+import org.json4s.native.JsonMethods._
+
+object Genome {
+  def save(path: String, genome: Array[Double]): Unit = {
+    val json = compact(render(JArray(genome.map(d => JDouble(d): JValue).toList)))
+    Files.write(Paths.get(path), json.getBytes(StandardCharsets.UTF_8))
+  }
+
+  def load(path: String): Array[Double] = {
+    val json = new String(Files.readAllBytes(Paths.get(path)), StandardCharsets.UTF_8)
+    val ast = try parse(json) catch {
+      case ex: Exception => throw new MalformedNeuronException("Malformed JSON: " + ex.getMessage)
+    }
+    ast match {
+      case JArray(xs) => xs.map {
+        case JDouble(d) => d
+        case JInt(i) => i.toDouble
+        case v => throw new MalformedNeuronException("Requirement: number expected but " + v)
+      }.toArray
+      case v => throw new MalformedNeuronException("Requirement: genome must be a JSON array but " + v)
+    }
+  }
+}
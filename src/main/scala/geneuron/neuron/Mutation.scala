@@ -0,0 +1,33 @@
+package geneuron.neuron
+
+import scala.util.Random
+
+sealed trait Mutation {
+  def name: String
+  def apply(gene: Array[Double], rate: Double, random: Random = Random): Array[Double]
+}
+
+object Mutation {
+  private val GaussianName = """gaussian\((.+)\)""".r
+
+  def fromName(name: String): Option[Mutation] = name match {
+    case Uniform.name => Some(Uniform)
+    case GaussianName(sigma) => scala.util.Try(sigma.toDouble).toOption.map(Gaussian)
+    case _ => None
+  }
+
+  /** Perturbs each gene with probability rate by a uniform offset in [-0.5, 0.5). */
+  case object Uniform extends Mutation {
+    val name = "uniform"
+    override def apply(gene: Array[Double], rate: Double, random: Random): Array[Double] = {
+      gene.map(g => if (random.nextDouble() < rate) g + random.nextDouble() - 0.5 else g)
+    }
+  }
+  /** Perturbs each gene with probability rate by gaussian noise of standard deviation sigma. */
+  case class Gaussian(sigma: Double) extends Mutation {
+    val name = s"gaussian($sigma)"
+    override def apply(gene: Array[Double], rate: Double, random: Random): Array[Double] = {
+      gene.map(g => if (random.nextDouble() < rate) g + random.nextGaussian() * sigma else g)
+    }
+  }
+}
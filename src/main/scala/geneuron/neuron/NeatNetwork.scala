@@ -0,0 +1,137 @@
+package geneuron.neuron
+
+import scala.util.Random
+
+case class Connection(from: Int, to: Int, weight: Double, enabled: Boolean, innovation: Int)
+
+/** Innovation numbers of one population; each world or test keeps its own so counters never leak between them. */
+class InnovationTracker(start: Int = 0) {
+  private var last = start
+  def next(): Int = synchronized {
+    last += 1
+    last
+  }
+  /** Moves the counter past innovation so it is never handed out again. */
+  def observe(innovation: Int): Unit = synchronized {
+    last = math.max(last, innovation)
+  }
+  def lastInnovation: Int = synchronized(last)
+}
+
+/**
+ * Genome layout
+ * 0: Input count
+ * 1: Output count
+ * 2: Hidden count
+ * 3: Connection count
+ * 4-: (from, to, weight, enabled, innovation) for each connection
+ */
+object NeatNetwork {
+  /** Fully connects every input to every output with random weights and no hidden nodes. */
+  def initial(inputs: Int, outputs: Int, tracker: InnovationTracker, random: Random = Random): NeatNetwork = {
+    val connections = for (i <- 0 until inputs; o <- 0 until outputs)
+      yield Connection(i, inputs + o, random.nextDouble() * 2 - 1, enabled = true, i * outputs + o + 1)
+    tracker.observe(inputs * outputs)
+    new NeatNetwork(inputs, outputs, 0, connections.toVector)
+  }
+
+  /** Nodes in dependency order over enabled connections, or None if they form a cycle. */
+  def sorted(nodes: Int, connections: Seq[Connection]): Option[Seq[Int]] = {
+    val enabled = connections.filter(_.enabled)
+    val incoming = Array.fill(nodes)(0)
+    enabled.foreach(c => incoming(c.to) += 1)
+    val ready = scala.collection.mutable.Queue((0 until nodes).filter(incoming(_) == 0): _*)
+    val sorted = scala.collection.mutable.ArrayBuffer.empty[Int]
+    while (ready.nonEmpty) {
+      val n = ready.dequeue()
+      sorted += n
+      enabled.filter(_.from == n).foreach { c =>
+        incoming(c.to) -= 1
+        if (incoming(c.to) == 0) ready.enqueue(c.to)
+      }
+    }
+    if (sorted.length == nodes) Some(sorted) else None
+  }
+
+  def fromGenome(genome: Array[Double], tracker: InnovationTracker, activation: Activation = Activation.Sigmoid, maxParameters: Int = NeuronCluster.maxParameters): NeatNetwork = {
+    if (genome.length < 4) throw new IllegalArgumentException("Requirement: genome has a 4 value header")
+    val Array(inputs, outputs, hidden, count) = genome.take(4).map(_.toInt)
+    if (maxParameters < count) throw new IllegalArgumentException(s"Requirement: NeatNetwork must have at most $maxParameters parameters but $count")
+    if (genome.length != 4 + count * 5) throw new IllegalArgumentException("Requirement: genome length equals 4+connections*5")
+    val connections = genome.drop(4).grouped(5).map {
+      case Array(from, to, weight, enabled, innovation) => Connection(from.toInt, to.toInt, weight, enabled != 0.0, innovation.toInt)
+    }.toVector
+    connections.foreach(c => tracker.observe(c.innovation))
+    new NeatNetwork(inputs, outputs, hidden, connections, activation, maxParameters)
+  }
+}
+
+/** Graph network grown by NEAT-style structural mutation; nodes are inputs, then outputs, then hidden. */
+class NeatNetwork(val inputs: Int, val outputs: Int, val hidden: Int, val connections: Vector[Connection], val activation: Activation = Activation.Sigmoid, val maxParameters: Int = NeuronCluster.maxParameters) extends Neuron {
+  val nodes = inputs + outputs + hidden
+  if (connections.exists(c => c.from < 0 || nodes <= c.from || c.to < inputs || nodes <= c.to)) throw new IllegalArgumentException("Requirement: connections join existing nodes and never end at an input")
+  val parameterCount = connections.length
+  if (maxParameters < parameterCount) throw new IllegalArgumentException(s"Requirement: NeatNetwork must have at most $maxParameters parameters but $parameterCount")
+
+  /** Non-input nodes in dependency order over enabled connections. */
+  val order: Seq[Int] = NeatNetwork.sorted(nodes, connections).getOrElse {
+    throw new IllegalArgumentException("Requirement: enabled connections are acyclic")
+  }.filter(inputs <= _)
+
+  /** Enabled connections grouped by the node they feed. */
+  val incoming: Map[Int, Seq[Connection]] = connections.filter(_.enabled).groupBy(_.to).withDefaultValue(Nil)
+
+  override def process(in: Array[Double]): Array[Double] = {
+    val values = Array.fill(nodes)(0.0)
+    Array.copy(in, 0, values, 0, inputs)
+    order.foreach { n =>
+      values(n) = activation(incoming(n).map(c => values(c.from) * c.weight).sum)
+    }
+    values.slice(inputs, inputs + outputs)
+  }
+
+  def genome: Array[Double] = {
+    Array[Double](inputs, outputs, hidden, connections.length) ++ connections.flatMap { c =>
+      Seq(c.from.toDouble, c.to.toDouble, c.weight, if (c.enabled) 1.0 else 0.0, c.innovation.toDouble)
+    }
+  }
+
+  def perturb(rate: Double, random: Random = Random): NeatNetwork = {
+    copy(connections.map(c => if (random.nextDouble() < rate) c.copy(weight = c.weight + random.nextDouble() - 0.5) else c))
+  }
+
+  /**
+   * Adds a random connection that does not exist yet and keeps the graph acyclic; unchanged if none fits or the budget is spent.
+   * Only the kept connection takes an innovation number from tracker
+   */
+  def addConnection(tracker: InnovationTracker, random: Random = Random): NeatNetwork = {
+    if (maxParameters <= parameterCount) this else {
+      val existing = connections.map(c => (c.from, c.to)).toSet
+      val candidates = for {
+        from <- (0 until nodes).filterNot(n => inputs <= n && n < inputs + outputs)
+        to <- inputs until nodes
+        if from != to && !existing((from, to))
+      } yield (from, to)
+      random.shuffle(candidates).find { case (from, to) =>
+        NeatNetwork.sorted(nodes, connections :+ Connection(from, to, 0.0, enabled = true, 0)).isDefined
+      }.fold(this) { case (from, to) =>
+        copy(connections :+ Connection(from, to, random.nextDouble() * 2 - 1, enabled = true, tracker.next()))
+      }
+    }
+  }
+
+  /** Splits a random enabled connection with a new hidden node; unchanged if there is none or the budget is spent. */
+  def addNode(tracker: InnovationTracker, random: Random = Random): NeatNetwork = {
+    val enabled = connections.filter(_.enabled)
+    if (enabled.isEmpty || maxParameters < parameterCount + 2) this else {
+      val split = enabled(random.nextInt(enabled.length))
+      val node = nodes
+      new NeatNetwork(inputs, outputs, hidden + 1, connections.map(c => if (c == split) c.copy(enabled = false) else c) ++ Seq(
+        Connection(split.from, node, 1.0, enabled = true, tracker.next()),
+        Connection(node, split.to, split.weight, enabled = true, tracker.next())
+      ), activation, maxParameters)
+    }
+  }
+
+  private def copy(connections: Vector[Connection]): NeatNetwork = new NeatNetwork(inputs, outputs, hidden, connections, activation, maxParameters)
+}
@@ -2,4 +2,9 @@ package geneuron.neuron
 
 trait Neuron {
   def process(in: Array[Double]): Array[Double]
+  /**
+   * Processes independent inputs, giving the same rows as ins.map(process);
+   * recurrent neurons carry state from one input to the next and reject batches
+   */
+  def processBatch(ins: Array[Array[Double]]): Array[Array[Double]] = ins.map(process)
 }
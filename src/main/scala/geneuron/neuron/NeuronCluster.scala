@@ -1,11 +1,61 @@
 package geneuron.neuron
 
-class NeuronCluster(layers: Seq[Int], array: Array[Double]) extends Neuron {
+import org.json4s._
+import org.json4s.native.JsonMethods._
+
+class MalformedNeuronException(message: String) extends IllegalArgumentException(message)
+
+object NeuronCluster {
+  val maxDepth = 16
+  val maxParameters = 100000
+
+  def parameterCount(layers: Seq[Int]): Int = (layers zip layers.tail).map { case (ins, outs) => outs * (ins + 1) }.sum
+
+  def fromJson(json: String): NeuronCluster = {
+    def fail(message: String) = throw new MalformedNeuronException(message)
+    def number(v: JValue): Double = v match {
+      case JDouble(d) => d
+      case JInt(i) => i.toDouble
+      case _ => fail("Requirement: number expected but " + v)
+    }
+    def array(v: JValue): List[JValue] = v match {
+      case JArray(xs) => xs
+      case _ => fail("Requirement: array expected but " + v)
+    }
+    val ast = try parse(json) catch {
+      case ex: Exception => fail("Malformed JSON: " + ex.getMessage)
+    }
+    val layers = array(ast \ "layers").map(number(_).toInt)
+    val activation = ast \ "activation" match {
+      case JNothing => Activation.Sigmoid
+      case JString(name) => Activation.fromName(name).getOrElse(fail("Unknown activation: " + name))
+      case v => fail("Requirement: activation name expected but " + v)
+    }
+    val weights = array(ast \ "weights").map(array(_).map(array(_).map(number)))
+    val biases = array(ast \ "biases").map(array(_).map(number))
+    if (layers.length < 2) fail("Requirement: NeuronCluster must have more than 2 layers")
+    if (weights.length != layers.length - 1 || biases.length != layers.length - 1) fail("Requirement: one weight matrix and bias vector per layer")
+    val data = ((layers zip layers.tail) zip (weights zip biases)).flatMap {
+      case ((ins, outs), (omega, bias)) =>
+        if (omega.length != ins || omega.exists(_.length != outs)) fail(s"Requirement: weight matrix of $ins x $outs")
+        if (bias.length != outs) fail(s"Requirement: bias vector of $outs")
+        omega.transpose.flatten ++ bias
+    }
+    try new NeuronCluster(layers, data.toArray, activation) catch {
+      case ex: IllegalArgumentException => fail(ex.getMessage)
+    }
+  }
+}
+
+class NeuronCluster(val layers: Seq[Int], array: Array[Double], val activation: Activation = Activation.Sigmoid, maxDepth: Int = NeuronCluster.maxDepth, maxParameters: Int = NeuronCluster.maxParameters) extends Neuron {
   if (layers.length < 2) throw new IllegalArgumentException("Requirement: NeuronCluster must have more than 2 layers")
+  if (maxDepth < layers.length - 1) throw new IllegalArgumentException(s"Requirement: NeuronCluster must have at most $maxDepth weight layers")
+  val parameterCount = NeuronCluster.parameterCount(layers)
+  if (maxParameters < parameterCount) throw new IllegalArgumentException(s"Requirement: NeuronCluster must have at most $maxParameters parameters but $parameterCount")
   val neuronLayers: Seq[NeuronLayer] = (layers zip layers.tail).foldLeft[(Array[Double], List[NeuronLayer])](array, Nil) {
     case ((ary, list), (ins, outs)) =>
       val consumeCount = outs * (ins + 1)
-      val neuronLayer = new NeuronLayer(ins, outs, ary.take(consumeCount))
+      val neuronLayer = new NeuronLayer(ins, outs, ary.take(consumeCount), activation)
       (ary.drop(consumeCount), neuronLayer :: list)
   }._2.reverse
 
@@ -14,4 +64,25 @@ class NeuronCluster(layers: Seq[Int], array: Array[Double]) extends Neuron {
       neuronLayer.process(in)
     }
   }
+
+  override def processBatch(ins: Array[Array[Double]]): Array[Array[Double]] = {
+    neuronLayers.foldLeft(ins) { (ins, neuronLayer) =>
+      neuronLayer.processBatch(ins)
+    }
+  }
+
+  def toJson: String = {
+    val weights = neuronLayers.map { l =>
+      JArray((0 until l.ins).map(i => JArray((0 until l.outs).map(j => JDouble(l.omega(i, j)): JValue).toList)).toList)
+    }
+    val biases = neuronLayers.map { l =>
+      JArray((0 until l.outs).map(j => JDouble(l.bias(0, j)): JValue).toList)
+    }
+    compact(render(JObject(
+      "layers" -> JArray(layers.map(n => JInt(n): JValue).toList),
+      "activation" -> JString(activation.name),
+      "weights" -> JArray(weights.toList),
+      "biases" -> JArray(biases.toList)
+    )))
+  }
 }
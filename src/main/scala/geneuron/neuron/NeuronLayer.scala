@@ -1,15 +1,19 @@
 package geneuron.neuron
 
 import breeze.linalg.DenseMatrix
-import breeze.numerics._
 
-class NeuronLayer(ins: Int, outs: Int, array: Array[Double]) extends Neuron {
+class NeuronLayer(val ins: Int, val outs: Int, array: Array[Double], val activation: Activation = Activation.Sigmoid) extends Neuron {
   if (array.length != outs * (ins + 1)) throw new IllegalArgumentException("Requirement: array length equals outs*(ins+1)")
   val omega = new DenseMatrix[Double](ins, outs, array, 0)
   val bias = new DenseMatrix[Double](1, outs, array, ins * outs)
   val inMx = new DenseMatrix[Double](1, ins)
   override def process(in: Array[Double]): Array[Double] = {
     Array.copy(in, 0, inMx.data, 0, ins)
-    (1.0 / (exp((inMx * omega + bias) * -1.0) + 1.0)).data
+    (inMx * omega + bias).data.map(activation)
+  }
+  override def processBatch(batch: Array[Array[Double]]): Array[Array[Double]] = if (batch.isEmpty) Array.empty else {
+    val inBatch = DenseMatrix.tabulate[Double](batch.length, ins)((r, c) => batch(r)(c))
+    val out = inBatch * omega
+    Array.tabulate(batch.length, outs)((r, c) => activation(out(r, c) + bias(0, c)))
   }
 }
@@ -0,0 +1,33 @@
+package geneuron.neuron
+
+object RecurrentNeuronCluster {
+  def parameterCount(layers: Seq[Int]): Int = (layers zip layers.tail).map { case (ins, outs) => outs * (ins + outs + 1) }.sum
+}
+
+class RecurrentNeuronCluster(val layers: Seq[Int], array: Array[Double], val activation: Activation = Activation.Sigmoid, maxDepth: Int = NeuronCluster.maxDepth, maxParameters: Int = NeuronCluster.maxParameters) extends Neuron {
+  if (layers.length < 2) throw new IllegalArgumentException("Requirement: RecurrentNeuronCluster must have more than 2 layers")
+  if (maxDepth < layers.length - 1) throw new IllegalArgumentException(s"Requirement: RecurrentNeuronCluster must have at most $maxDepth weight layers")
+  val parameterCount = RecurrentNeuronCluster.parameterCount(layers)
+  if (maxParameters < parameterCount) throw new IllegalArgumentException(s"Requirement: RecurrentNeuronCluster must have at most $maxParameters parameters but $parameterCount")
+  val neuronLayers: Seq[RecurrentNeuronLayer] = (layers zip layers.tail).foldLeft[(Array[Double], List[RecurrentNeuronLayer])](array, Nil) {
+    case ((ary, list), (ins, outs)) =>
+      val consumeCount = outs * (ins + outs + 1)
+      val neuronLayer = new RecurrentNeuronLayer(ins, outs, ary.take(consumeCount), activation)
+      (ary.drop(consumeCount), neuronLayer :: list)
+  }._2.reverse
+
+  override def process(in: Array[Double]): Array[Double] = {
+    neuronLayers.foldLeft(in) { (in, neuronLayer) =>
+      neuronLayer.process(in)
+    }
+  }
+
+  override def processBatch(ins: Array[Array[Double]]): Array[Array[Double]] = {
+    throw new UnsupportedOperationException("RecurrentNeuronCluster carries state between inputs; process them one at a time")
+  }
+
+  def reset(): Unit = neuronLayers.foreach(_.reset())
+
+  /** Every layer's parameters in constructor order, so the same layers and genome rebuild this cluster. */
+  def genome: Array[Double] = neuronLayers.flatMap(_.genome).toArray
+}
@@ -0,0 +1,27 @@
+package geneuron.neuron
+
+import breeze.linalg.DenseMatrix
+
+/** NeuronLayer whose previous outputs are fed back in alongside the next inputs. */
+class RecurrentNeuronLayer(val ins: Int, val outs: Int, array: Array[Double], val activation: Activation = Activation.Sigmoid) extends Neuron {
+  if (array.length != outs * (ins + outs + 1)) throw new IllegalArgumentException("Requirement: array length equals outs*(ins+outs+1)")
+  val omega = new DenseMatrix[Double](ins, outs, array, 0)
+  val recurrent = new DenseMatrix[Double](outs, outs, array, ins * outs)
+  val bias = new DenseMatrix[Double](1, outs, array, (ins + outs) * outs)
+  val inMx = new DenseMatrix[Double](1, ins)
+  val state = new DenseMatrix[Double](1, outs)
+  override def process(in: Array[Double]): Array[Double] = {
+    Array.copy(in, 0, inMx.data, 0, ins)
+    val out = (inMx * omega + state * recurrent + bias).data.map(activation)
+    Array.copy(out, 0, state.data, 0, outs)
+    out
+  }
+  override def processBatch(ins: Array[Array[Double]]): Array[Array[Double]] = {
+    throw new UnsupportedOperationException("RecurrentNeuronLayer carries state between inputs; process them one at a time")
+  }
+  /** Weights, recurrent weights and biases in the order the constructor reads them. */
+  def genome: Array[Double] = omega.toArray ++ recurrent.toArray ++ bias.toArray
+  def reset(): Unit = {
+    java.util.Arrays.fill(state.data, 0.0)
+  }
+}
@@ -0,0 +1,22 @@
+package geneuron.neuron
+
+object Speciation {
+  def genomeDistance(a: Array[Double], b: Array[Double]): Double = {
+    if (a.length != b.length) throw new IllegalArgumentException("Requirement: genomes of equal length")
+    math.sqrt((a zip b).map { case (x, y) => (x - y) * (x - y) }.sum)
+  }
+
+  /**
+   * Species number for each key, joining the first species whose founder is within threshold
+   * or founding a new one; keys are visited in order so the result is deterministic
+   */
+  def assign[K](genomes: Seq[(K, Array[Double])], threshold: Double): Map[K, Int] = {
+    genomes.foldLeft((Vector.empty[Array[Double]], Map.empty[K, Int])) {
+      case ((founders, species), (key, genome)) =>
+        founders.indexWhere(genomeDistance(_, genome) <= threshold) match {
+          case -1 => (founders :+ genome, species + (key -> founders.length))
+          case i => (founders, species + (key -> i))
+        }
+    }._2
+  }
+}
@@ -0,0 +1,229 @@
+package geneuron
+
+import java.io.File
+import java.nio.file.Files
+
+import geneuron.creature.{Creature, Physics, SensorConfig}
+import geneuron.neuron.Genome
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class GeneuronTest extends FunSuite {
+  def world(config: WorldConfig = WorldConfig()): Geneuron = new Geneuron("Geneuron", seed = Some(1L), config = config)
+
+  /** A still creature whose centered weights keep its decisions away from saturation. */
+  def creature(id: Long, x: Float, y: Float): Creature = {
+    val random = new Random(id)
+    new Creature(new Physics(x, y, 0.0F, 0.0F, 0.0F, 1.0F), Array.fill(Creature.geneLength(SensorConfig()))(random.nextDouble() - 0.5), random)(id, sensePhase = 0)
+  }
+
+  /** Every creature's physics, decisions and gene by id, for comparing two worlds. */
+  def state(geneuron: Geneuron): Map[Long, (Seq[Float], Seq[Float], Seq[Double])] = geneuron.creatures.seq.toSeq.map { c =>
+    val p = c.physics
+    c.id -> (Seq(p.x, p.y, p.vx, p.vy, p.deg, p.energy, p.distance), Seq(c.viewingAngle, c.leftProp, c.rightProp, if (c.intention) 1.0F else 0.0F), c.gene.toSeq)
+  }.toMap
+
+  /** Number of values differing from the one before, starting from the initial 0. */
+  def changes(xs: Seq[Float]): Int = (0.0F +: xs).sliding(2).count { case Seq(a, b) => a != b }
+
+  test("LOD mode updates off-screen creatures less often than on-screen ones") {
+    val geneuron = world()
+    geneuron.viewpoint.lod = true
+    val onScreen = creature(1L, 100.0F, 100.0F)
+    val offScreen = creature(2L, 10000.0F, 10000.0F)
+    geneuron.creatures ++= Seq(onScreen, offScreen)
+    val steps = Coefficient.lodInterval * 4
+    val angles = (1 to steps).map { _ =>
+      geneuron.step()
+      (onScreen.viewingAngle, offScreen.viewingAngle)
+    }
+    assert(changes(angles.map(_._1)) == steps)
+    assert(changes(angles.map(_._2)) == steps / Coefficient.lodInterval)
+  }
+
+  test("initial creatures spawn at least the minimum distance apart in a sparse world") {
+    val geneuron = world()
+    geneuron.populate()
+    val positions = geneuron.creatures.seq.toSeq.map(c => (c.physics.x, c.physics.y))
+    assert(positions.length == geneuron.config.initialPopulation)
+    positions.combinations(2).foreach { case Seq((x1, y1), (x2, y2)) =>
+      assert(geneuron.config.spawnDistance <= math.hypot(x1 - x2, y1 - y2))
+    }
+  }
+
+  test("every initial creature shares the genome loaded from file") {
+    val file = File.createTempFile("geneuron-genome", ".json")
+    try {
+      val genome = Creature.randomGene(new Random(1))
+      Genome.save(file.getPath, genome)
+      val geneuron = new Geneuron("Geneuron", Some(file.getPath), Some(1L))
+      geneuron.populate()
+      assert(geneuron.creatures.nonEmpty)
+      assert(geneuron.creatures.seq.forall(_.gene.toSeq == genome.toSeq))
+    } finally file.delete()
+  }
+
+  test("an initial genome that does not match the topology is rejected") {
+    val file = File.createTempFile("geneuron-genome", ".json")
+    try {
+      Genome.save(file.getPath, Array(1.0, 2.0))
+      intercept[IllegalArgumentException](new Geneuron("Geneuron", Some(file.getPath), Some(1L)).populate())
+    } finally file.delete()
+  }
+
+  test("a lower render frame rate runs more steps per frame without changing simulated time per step") {
+    val config = WorldConfig(stepRate = 60, renderFps = 20)
+    val geneuron = world(config)
+    (1 to 3).foreach(_ => geneuron.advance())
+    assert(geneuron.frame == 9)
+    assert(math.abs(geneuron.time - 9.0F / 60) < 1e-6)
+    assert(WorldConfig(stepRate = 60, renderFps = 120).stepsPerFrame == 0.5F)
+  }
+
+  test("simulation steps per rendered frame follow the time scale") {
+    val fast = world()
+    fast.setTimeScale(4.0F)
+    (1 to 3).foreach(_ => fast.advance())
+    assert(fast.frame == 3 * 4)
+    val slow = world()
+    slow.setTimeScale(0.25F)
+    (1 to 8).foreach(_ => slow.advance())
+    assert(slow.frame == 2)
+    slow.togglePause()
+    (1 to 8).foreach(_ => slow.advance())
+    assert(slow.frame == 2)
+  }
+
+  test("autosave writes a snapshot every interval and prunes all but the newest") {
+    val dir = Files.createTempDirectory("geneuron-autosave").toFile
+    try {
+      val geneuron = world()
+      geneuron.populate()
+      geneuron.enableAutosave(dir.getPath, 1.0F, 2)
+      val stepsPerSecond = geneuron.config.stepRate
+      (1 to stepsPerSecond * 5 + 1).foreach(_ => geneuron.step())
+      val frames = geneuron.autosave.get.snapshots.map(_.getName.filter(_.isDigit).toLong)
+      assert(frames.length == 2)
+      assert(frames.forall(stepsPerSecond * 3 < _))
+      val loaded = world()
+      Snapshot.load(geneuron.autosave.get.snapshots.last.getPath, loaded)
+      assert(loaded.frame == frames.last)
+    } finally {
+      Option(dir.listFiles).foreach(_.foreach(_.delete()))
+      dir.delete()
+    }
+  }
+
+  test("two worlds with the same seed evolve identically") {
+    val (a, b) = (world(), world())
+    a.populate()
+    b.populate()
+    (1 to 120).foreach { _ =>
+      a.step()
+      b.step()
+    }
+    assert(state(a).nonEmpty)
+    assert(state(a) == state(b))
+    assert(a.species == b.species)
+    assert(a.history.toSeq == b.history.toSeq)
+  }
+
+  test("a loaded snapshot continues exactly as the saved world and starts a fresh history") {
+    val file = File.createTempFile("geneuron-snapshot", ".json")
+    try {
+      val saved = world()
+      saved.populate()
+      (1 to 30).foreach(_ => saved.step())
+      Snapshot.save(file.getPath, saved)
+      val loaded = world()
+      (1 to 5).foreach(_ => loaded.step())
+      Snapshot.load(file.getPath, loaded)
+      assert(loaded.history.length == 0)
+      (1 to 60).foreach { _ =>
+        saved.step()
+        loaded.step()
+      }
+      assert(loaded.frame == saved.frame)
+      assert(state(loaded) == state(saved))
+      assert(loaded.history.toSeq.map(_.frame) == (31L to 90L))
+      assert(loaded.history.toSeq == saved.history.latest(60))
+    } finally file.delete()
+  }
+
+  test("autosave is rescheduled from the loaded time") {
+    val dir = Files.createTempDirectory("geneuron-autosave").toFile
+    val file = File.createTempFile("geneuron-snapshot", ".json")
+    try {
+      val saved = world()
+      (1 to 10).foreach(_ => saved.step())
+      Snapshot.save(file.getPath, saved)
+      val loaded = world()
+      loaded.enableAutosave(dir.getPath, 1.0F, 2)
+      (1 to 50).foreach(_ => loaded.step())
+      Snapshot.load(file.getPath, loaded)
+      (1 to loaded.config.stepRate - 1).foreach(_ => loaded.step())
+      assert(loaded.autosave.get.snapshots.isEmpty)
+      (1 to 20).foreach(_ => loaded.step())
+      assert(loaded.autosave.get.snapshots.length == 1)
+    } finally {
+      file.delete()
+      Option(dir.listFiles).foreach(_.foreach(_.delete()))
+      dir.delete()
+    }
+  }
+
+  test("neural cost is charged every step even when decisions are held") {
+    val config = WorldConfig(senseInterval = 3, neuralCost = 1e-6F)
+    val geneuron = world(config)
+    geneuron.viewpoint.lod = true
+    val onScreen = creature(1L, 100.0F, 100.0F)
+    val offScreen = creature(2L, 10000.0F, 10000.0F)
+    geneuron.creatures ++= Seq(onScreen, offScreen)
+    val cost = config.neuralCost * onScreen.neuron.parameterCount
+    (1 to 12).foreach { _ =>
+      val before = (onScreen.physics.energy, offScreen.physics.energy)
+      geneuron.step()
+      assert(math.abs(before._1 - onScreen.physics.energy - cost) < 1e-6)
+      assert(math.abs(before._2 - offScreen.physics.energy - cost) < 1e-6)
+    }
+  }
+
+  test("a creature repeatedly intending to reproduce loses courtship energy every step") {
+    val config = WorldConfig(courtshipCost = 0.01F)
+    val geneuron = world(config)
+    val gene = Array.fill(Creature.geneLength(config.sensors))(0.0)
+    gene(gene.length - 1) = 10.0 // Bias of the intention output
+    val courting = new Creature(new Physics(100.0F, 100.0F, 0.0F, 0.0F, 0.0F, 1.0F), gene, new Random(1), config)(1L)
+    val idle = new Creature(new Physics(300.0F, 300.0F, 0.0F, 0.0F, 0.0F, 1.0F), Array.fill(gene.length)(0.0), new Random(2), config)(2L)
+    geneuron.creatures ++= Seq(courting, idle)
+    (1 to 10).foreach(_ => geneuron.step())
+    assert(courting.intention && !idle.intention)
+    assert(math.abs(courting.physics.energy - 0.9F) < 1e-5)
+    assert(idle.physics.energy == 1.0F)
+  }
+
+  test("daylight is 0 at midnight and 1 at noon") {
+    val geneuron = world()
+    assert(geneuron.daylight == 0.0F)
+    geneuron.frame = geneuron.config.dayLength / 2
+    assert(geneuron.daylight == 1.0F)
+    geneuron.frame = geneuron.config.dayLength
+    assert(geneuron.daylight == 0.0F)
+  }
+
+  test("night metabolism raises the per-step cost at night") {
+    val config = WorldConfig(neuralCost = 1e-6F, nightMetabolism = 1.0F)
+    def drop(frame: Long): Float = {
+      val geneuron = world(config)
+      val c = creature(1L, 100.0F, 100.0F)
+      geneuron.creatures += c
+      geneuron.frame = frame
+      geneuron.step()
+      1.0F - c.physics.energy
+    }
+    val midnight = drop(config.dayLength - 1)
+    val noon = drop(config.dayLength / 2 - 1)
+    assert(math.abs(midnight - 2 * noon) < 1e-5)
+  }
+}
@@ -0,0 +1,35 @@
+package geneuron
+
+import org.scalatest.FunSuite
+
+class ViewPointTest extends FunSuite {
+  test("a clustered population is framed with a higher zoom than a dispersed one") {
+    val (clustered, _, _) = ViewPoint.fit(640.0F, 480.0F, Seq((300.0F, 200.0F), (310.0F, 210.0F), (305.0F, 190.0F)))
+    val (dispersed, _, _) = ViewPoint.fit(640.0F, 480.0F, Seq((0.0F, 0.0F), (1200.0F, 900.0F), (600.0F, 450.0F)))
+    assert(dispersed < clustered)
+    assert(Coefficient.minZoom <= dispersed && clustered <= Coefficient.maxZoom)
+  }
+
+  test("the framed view contains every position") {
+    val positions = Seq((-50.0F, 20.0F), (900.0F, 300.0F), (100.0F, 700.0F))
+    val (zoom, x, y) = ViewPoint.fit(640.0F, 480.0F, positions)
+    positions.foreach { case (px, py) =>
+      assert(x <= px && px <= x + 640.0F / zoom)
+      assert(y <= py && py <= y + 480.0F / zoom)
+    }
+  }
+
+  test("between two keyframes the camera is their linear interpolation") {
+    val path = Seq(ViewPoint.Keyframe(0.0F, 0.0F, 100.0F, 1.0F), ViewPoint.Keyframe(2.0F, 200.0F, 300.0F, 3.0F))
+    assert(ViewPoint.interpolate(path, 0.5F) == (1.5F, 50.0F, 150.0F))
+    assert(ViewPoint.interpolate(path, -1.0F) == (1.0F, 0.0F, 100.0F))
+    assert(ViewPoint.interpolate(path, 5.0F) == (3.0F, 200.0F, 300.0F))
+  }
+
+  test("touring follows the camera path") {
+    val viewpoint = new ViewPoint
+    viewpoint.setCameraPath(Seq(ViewPoint.Keyframe(4.0F, 400.0F, 0.0F, 2.0F), ViewPoint.Keyframe(0.0F, 0.0F, 0.0F, 1.0F)))
+    viewpoint.tour(1.0F)
+    assert((viewpoint.zoom, viewpoint.x, viewpoint.y) == (1.25F, 100.0F, 0.0F))
+  }
+}
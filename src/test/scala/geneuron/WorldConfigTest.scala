@@ -0,0 +1,27 @@
+package geneuron
+
+import geneuron.neuron.{Crossover, Mutation}
+import org.scalatest.FunSuite
+
+class WorldConfigTest extends FunSuite {
+  test("properties override their fields and leave the rest at defaults") {
+    val config = WorldConfig.fromProperties(Map(
+      "geneuron.initialPopulation" -> "25",
+      "geneuron.senseInterval" -> "3",
+      "geneuron.courtshipCost" -> "0.01",
+      "geneuron.crossover" -> "two_point",
+      "geneuron.mutation" -> "gaussian(0.2)",
+      "geneuron.sensors.vision" -> "8",
+      "geneuron.unrelated" -> "ignored"
+    ))
+    assert(config == WorldConfig(initialPopulation = 25, senseInterval = 3, courtshipCost = 0.01F,
+      crossover = Crossover.TwoPoint, mutation = Mutation.Gaussian(0.2), sensors = WorldConfig().sensors.copy(vision = 8)))
+    assert(WorldConfig.fromProperties(Map.empty) == WorldConfig())
+  }
+
+  test("invalid property values are rejected") {
+    intercept[IllegalArgumentException](WorldConfig.fromProperties(Map("geneuron.width" -> "wide")))
+    intercept[IllegalArgumentException](WorldConfig.fromProperties(Map("geneuron.crossover" -> "three_point")))
+    intercept[IllegalArgumentException](WorldConfig.fromProperties(Map("geneuron.senseInterval" -> "0")))
+  }
+}
@@ -0,0 +1,75 @@
+package geneuron.creature
+
+import geneuron.Coefficient
+import geneuron.neuron.NeuronCluster
+import org.newdawn.slick.Color
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class CreatureTest extends FunSuite {
+  val sensors = SensorConfig()
+
+  test("stepping the display color toward a target converges to it") {
+    val display = new Color(1.0F, 1.0F, 1.0F)
+    val target = new Color(0.2F, 0.4F, 0.6F)
+    (1 to 200).foreach(_ => Creature.lerp(display, target, Coefficient.colorLerp))
+    assert(math.abs(display.r - target.r) < 1e-3)
+    assert(math.abs(display.g - target.g) < 1e-3)
+    assert(math.abs(display.b - target.b) < 1e-3)
+  }
+
+  test("the energy bar width is the energy fraction of the full bar") {
+    assert(Creature.energyBarWidth(0.0F) == 0.0F)
+    assert(Creature.energyBarWidth(Coefficient.maxEnergy / 2) == Coefficient.energyBarWidth / 2)
+    assert(Creature.energyBarWidth(Coefficient.maxEnergy) == Coefficient.energyBarWidth)
+    assert(Creature.energyBarWidth(Coefficient.maxEnergy * 2) == Coefficient.energyBarWidth)
+    assert(Creature.energyBarWidth(-1.0F) == 0.0F)
+  }
+
+  test("with a sense interval of 3 decisions change at most every third frame") {
+    val random = new Random(1)
+    val creature = new Creature(new Physics(0.0F, 0.0F, 0.0F, 0.0F, 0.0F, 1.0F), Array.fill(Creature.geneLength(sensors))(random.nextDouble() - 0.5), random)(sensePhase = 1)
+    val angles = (1L to 12L).map { frame =>
+      if (creature.senseDue(frame, 3)) creature.process(creature.sense(sensors))
+      creature.viewingAngle
+    }
+    val changedAt = (1L to 12L).zip((0.0F +: angles).sliding(2).toSeq).collect { case (frame, Seq(a, b)) if a != b => frame }
+    assert(changedAt == Seq(2L, 5L, 8L, 11L))
+  }
+
+  test("heading is sensed as sin and cos, continuous across 0 and 360 degrees") {
+    def heading(deg: Float): (Float, Float) = {
+      val Array(_, sin, cos) = new Creature(new Physics(0.0F, 0.0F, 0.0F, 0.0F, deg, 1.0F))().proprioception
+      (sin, cos)
+    }
+    def near(a: (Float, Float), b: (Float, Float), tolerance: Double) = math.hypot(a._1 - b._1, a._2 - b._2) < tolerance
+    assert(near(heading(0.0F), (0.0F, 1.0F), 1e-6))
+    assert(near(heading(90.0F), (1.0F, 0.0F), 1e-6))
+    assert(near(heading(180.0F), (0.0F, -1.0F), 1e-6))
+    assert(near(heading(359.9F), heading(0.1F), 0.01))
+  }
+
+  test("speed is sensed as a fraction of the maximum speed") {
+    val Array(speed, _, _) = new Creature(new Physics(0.0F, 0.0F, 3.0F, 4.0F, 0.0F, 1.0F))().proprioception
+    assert(speed == 5.0F / Coefficient.maxSpeed)
+  }
+
+  test("a larger network costs more energy to run than a smaller one") {
+    val small = NeuronCluster.parameterCount(Seq(sensors.length, 5, 7))
+    val large = NeuronCluster.parameterCount(Creature.neuronLayers(sensors))
+    def cost(parameters: Int) = Creature.metabolicCost(parameters, intention = false, 1.0F, neural = 0.001F, courtship = 0.0F)
+    assert(small < large)
+    assert(cost(small) < cost(large))
+    assert(cost(large) < Creature.metabolicCost(large, intention = false, 2.0F, neural = 0.001F, courtship = 0.0F))
+  }
+
+  test("age colors run from cyan when newborn to red at and beyond the lifespan") {
+    def rgb(c: Color) = (c.r, c.g, c.b)
+    assert(rgb(Creature.ageColor(0L, 100L)) == (0.0F, 1.0F, 1.0F))
+    assert(rgb(Creature.ageColor(50L, 100L)) == (0.5F, 0.5F, 0.5F))
+    assert(rgb(Creature.ageColor(100L, 100L)) == (1.0F, 0.0F, 0.0F))
+    assert(rgb(Creature.ageColor(250L, 100L)) == (1.0F, 0.0F, 0.0F))
+    assert(Creature.ageFraction(Coefficient.expectedLifespan / 2, Long.MaxValue) == 0.5F)
+  }
+}
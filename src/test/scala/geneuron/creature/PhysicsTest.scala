@@ -0,0 +1,15 @@
+package geneuron.creature
+
+import org.scalatest.FunSuite
+
+class PhysicsTest extends FunSuite {
+  test("distance accumulates the length of every step") {
+    val physics = new Physics(0.0F, 0.0F, 3.0F, 4.0F, 0.0F, 1.0F)
+    (1 to 3).foreach(_ => physics.update())
+    physics.vx = -6.0F
+    physics.vy = 8.0F
+    physics.update()
+    assert(physics.distance == 25.0F)
+    assert((physics.x, physics.y) == (3.0F, 20.0F))
+  }
+}
@@ -0,0 +1,41 @@
+package geneuron.info
+
+import org.newdawn.slick.{Color, Font}
+import org.scalatest.FunSuite
+
+class NotificationTest extends FunSuite {
+  /** Monospaced font metrics without a display. */
+  object FixedFont extends Font {
+    def getWidth(str: String): Int = str.length * 8
+    def getHeight(str: String): Int = 12
+    def getLineHeight: Int = 14
+    def drawString(x: Float, y: Float, text: String): Unit = ()
+    def drawString(x: Float, y: Float, text: String, col: Color): Unit = ()
+    def drawString(x: Float, y: Float, text: String, col: Color, startIndex: Int, endIndex: Int): Unit = ()
+  }
+
+  test("the panel lists each population statistic on its own line") {
+    assert(Notification.lines(PopulationStats(3, 1.25F, 0.5F, 12.34F, 1)) == Seq(
+      "Creatures: 3",
+      "Total energy: 1.25",
+      "Energy: 0.50",
+      "Distance: 12.3",
+      "Reproducing: 1"
+    ))
+  }
+
+  test("doubling the UI scale doubles the panel size") {
+    val texts = Seq("Creatures: 3", "Energy: 0.50")
+    assert(Notification.panelSize(texts, FixedFont, 1.0F) == (96.0F, 28.0F))
+    assert(Notification.panelSize(texts, FixedFont, 2.0F) == (192.0F, 56.0F))
+  }
+
+  test("the top-left panel stays below the FPS line at every UI scale") {
+    Seq(1.0F, 2.0F, 4.0F).foreach { scale =>
+      val (x, y) = Notification.panelPosition(Notification.TopLeft, 96.0F * scale, 28.0F * scale, 640, 480, FixedFont, scale)
+      assert(x == 10F)
+      assert(y == 10F + FixedFont.getLineHeight * scale)
+    }
+    assert(Notification.panelPosition(Notification.BottomRight, 96.0F, 28.0F, 640, 480, FixedFont, 1.0F) == (534.0F, 442.0F))
+  }
+}
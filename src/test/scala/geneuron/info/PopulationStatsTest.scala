@@ -0,0 +1,31 @@
+package geneuron.info
+
+import geneuron.creature.{Creature, Physics}
+import org.scalatest.FunSuite
+
+class PopulationStatsTest extends FunSuite {
+  def creature(energy: Float, distance: Float = 0.0F, id: Long = 0L): Creature = {
+    val physics = new Physics(0.0F, 0.0F, 0.0F, 0.0F, 0.0F, energy)
+    physics.distance = distance
+    new Creature(physics)(id)
+  }
+
+  test("totals count every creature even when energies are equal") {
+    val stats = PopulationStats.of(collection.mutable.HashSet(creature(0.5F, 10.0F), creature(0.5F, 10.0F), creature(0.25F, 40.0F)))
+    assert(stats.population == 3)
+    assert(stats.totalEnergy == 1.25F)
+    assert(math.abs(stats.meanEnergy - 1.25F / 3) < 1e-6)
+    assert(stats.meanDistance == 20.0F)
+  }
+
+  test("an empty world has zero totals") {
+    val stats = PopulationStats.of(Nil)
+    assert(stats == PopulationStats(0, 0.0F, 0.0F, 0.0F, 0))
+  }
+
+  test("totals do not depend on iteration order") {
+    val creatures = Seq(creature(1e8F, id = 1L), creature(1.0F, id = 2L), creature(-1e8F, id = 3L))
+    assert(PopulationStats.of(creatures) == PopulationStats.of(creatures.reverse))
+    assert(PopulationStats.of(creatures) == PopulationStats.of(Seq(creatures(0), creatures(2), creatures(1))))
+  }
+}
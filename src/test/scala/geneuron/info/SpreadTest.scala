@@ -0,0 +1,17 @@
+package geneuron.info
+
+import org.scalatest.FunSuite
+
+class SpreadTest extends FunSuite {
+  val square = Seq((0.0F, 0.0F), (4.0F, 0.0F), (4.0F, 4.0F), (0.0F, 4.0F))
+
+  test("the centroid is the mean position") {
+    assert(Spread.centroid(square) == (2.0F, 2.0F))
+    assert(Spread.centroid(Seq((630.0F, 10.0F), (10.0F, 30.0F))) == (320.0F, 20.0F))
+  }
+
+  test("the deviation is the root mean square distance from the centroid") {
+    assert(math.abs(Spread.deviation(square) - math.sqrt(8.0)) < 1e-5)
+    assert(Spread.deviation(Seq((5.0F, 5.0F))) == 0.0F)
+  }
+}
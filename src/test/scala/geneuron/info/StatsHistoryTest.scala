@@ -0,0 +1,52 @@
+package geneuron.info
+
+import java.io.File
+import java.nio.file.Files
+
+import org.scalatest.FunSuite
+
+import scala.collection.JavaConverters._
+
+class StatsHistoryTest extends FunSuite {
+  def sample(frame: Long): StatsSample = StatsSample(frame, frame / 60.0F, PopulationStats(1, 0.5F, 0.5F, 0.0F, 0))
+
+  test("only the newest samples are kept, oldest first") {
+    val history = new StatsHistory(3)
+    (1L to 5L).foreach(f => history.record(sample(f)))
+    assert(history.toSeq.map(_.frame) == Seq(3L, 4L, 5L))
+    assert(history.latest(2).map(_.frame) == Seq(4L, 5L))
+    assert(history.latest(10).map(_.frame) == Seq(3L, 4L, 5L))
+  }
+
+  test("exporting twice appends only the samples recorded in between") {
+    val file = File.createTempFile("geneuron-stats", ".csv")
+    file.delete()
+    try {
+      val history = new StatsHistory(10)
+      (1L to 2L).foreach(f => history.record(sample(f)))
+      history.exportCsv(file.getPath)
+      history.exportCsv(file.getPath)
+      history.record(sample(3L))
+      history.exportCsv(file.getPath)
+      val lines = Files.readAllLines(file.toPath).asScala
+      assert(lines.head.startsWith("frame,time,"))
+      assert(lines.tail.map(_.split(",").head) == Seq("1", "2", "3"))
+    } finally file.delete()
+  }
+
+  test("clearing drops every sample and lets earlier frames be exported again") {
+    val file = File.createTempFile("geneuron-stats", ".csv")
+    file.delete()
+    try {
+      val history = new StatsHistory(10)
+      (1L to 5L).foreach(f => history.record(sample(f)))
+      history.exportCsv(file.getPath)
+      history.clear()
+      assert(history.length == 0)
+      (3L to 4L).foreach(f => history.record(sample(f)))
+      history.exportCsv(file.getPath)
+      val lines = Files.readAllLines(file.toPath).asScala
+      assert(lines.tail.map(_.split(",").head) == Seq("1", "2", "3", "4", "5", "3", "4"))
+    } finally file.delete()
+  }
+}
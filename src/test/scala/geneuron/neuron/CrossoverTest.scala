@@ -0,0 +1,25 @@
+package geneuron.neuron
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class CrossoverTest extends FunSuite {
+  val a = Array(1.0, 2.0, 3.0, 4.0, 5.0)
+  val b = Array(-1.0, -2.0, -3.0, -4.0, -5.0)
+
+  test("single point crossover is a prefix of the first parent followed by a suffix of the second") {
+    (0 to a.length).foreach { locus =>
+      assert(Crossover.singlePoint(a, b, locus).toSeq == a.take(locus).toSeq ++ b.drop(locus))
+    }
+    assert(Crossover.singlePoint(a, b, 2).toSeq == Seq(1.0, 2.0, -3.0, -4.0, -5.0))
+  }
+
+  test("every strategy keeps each gene from one of the parents at its position") {
+    Seq(Crossover.SinglePoint, Crossover.TwoPoint, Crossover.Uniform).foreach { crossover =>
+      val child = crossover(a, b, new Random(1))
+      assert(child.length == a.length)
+      assert(child.indices.forall(i => child(i) == a(i) || child(i) == b(i)))
+    }
+  }
+}
@@ -0,0 +1,34 @@
+package geneuron.neuron
+
+import java.io.File
+import java.nio.charset.StandardCharsets
+import java.nio.file.Files
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class GenomeTest extends FunSuite {
+  test("a saved genome loads into a network with equal outputs") {
+    val random = new Random(1)
+    val layers = Seq(3, 4, 2)
+    val gene = Array.fill(NeuronCluster.parameterCount(layers))(random.nextDouble())
+    val file = File.createTempFile("geneuron-genome", ".json")
+    try {
+      Genome.save(file.getPath, gene)
+      val in = Array.fill(3)(random.nextDouble())
+      val loaded = new NeuronCluster(layers, Genome.load(file.getPath))
+      assert(loaded.process(in).toSeq == new NeuronCluster(layers, gene).process(in).toSeq)
+    } finally file.delete()
+  }
+
+  test("malformed genome files are rejected with MalformedNeuronException") {
+    val file = File.createTempFile("geneuron-genome", ".json")
+    try {
+      Seq("[0.5, ", "{\"genome\": []}", "[0.5, \"x\"]").foreach { text =>
+        Files.write(file.toPath, text.getBytes(StandardCharsets.UTF_8))
+        intercept[MalformedNeuronException](Genome.load(file.getPath))
+      }
+    } finally file.delete()
+  }
+}
@@ -0,0 +1,23 @@
+package geneuron.neuron
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class MutationTest extends FunSuite {
+  val n = 100000
+
+  test("gaussian perturbations have zero mean and the configured spread") {
+    val offsets = Mutation.Gaussian(0.2)(Array.fill(n)(1.0), 1.0, new Random(1)).map(_ - 1.0)
+    val mean = offsets.sum / n
+    val sd = math.sqrt(offsets.map(d => (d - mean) * (d - mean)).sum / n)
+    assert(math.abs(mean) < 0.005)
+    assert(math.abs(sd - 0.2) < 0.005)
+  }
+
+  test("the rate is the fraction of perturbed genes") {
+    val mutated = Mutation.Gaussian(0.2)(Array.fill(n)(1.0), 0.1, new Random(1))
+    assert(math.abs(mutated.count(_ != 1.0).toDouble / n - 0.1) < 0.01)
+    assert(Mutation.Gaussian(0.2)(Array.fill(n)(1.0), 0.0, new Random(1)).forall(_ == 1.0))
+  }
+}
@@ -0,0 +1,61 @@
+package geneuron.neuron
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class NeatNetworkTest extends FunSuite {
+  val connections = Vector(Connection(0, 2, 0.5, enabled = true, 1), Connection(1, 2, -0.5, enabled = true, 2))
+
+  test("networks over the parameter budget are rejected") {
+    intercept[IllegalArgumentException](new NeatNetwork(2, 1, 0, connections, maxParameters = 1))
+    val network = new NeatNetwork(2, 1, 0, connections, maxParameters = 2)
+    intercept[IllegalArgumentException](NeatNetwork.fromGenome(network.genome, new InnovationTracker, maxParameters = 1))
+  }
+
+  test("structural mutation stops growing at the parameter budget") {
+    val tracker = new InnovationTracker(2)
+    val network = new NeatNetwork(2, 1, 0, connections, maxParameters = 3)
+    assert(network.addNode(tracker, new Random(1)) eq network)
+    val grown = (1 to 100).foldLeft(network)((n, _) => n.addConnection(tracker, new Random(1)).addNode(tracker, new Random(1)))
+    assert(grown.parameterCount <= 3)
+  }
+
+  test("loading a genome moves new innovations past the loaded ones") {
+    val tracker = new InnovationTracker
+    val loaded = new NeatNetwork(2, 1, 0, connections.map(c => c.copy(innovation = c.innovation + 1000000)))
+    NeatNetwork.fromGenome(loaded.genome, tracker)
+    assert(tracker.next() == 1000003)
+    assert(new InnovationTracker().next() == 1)
+  }
+
+  test("adding a connection takes exactly one innovation for the kept connection") {
+    // 0 -> 2 -> 3 -> 1 leaves 3 -> 2 as the one candidate that would close a cycle
+    val path = Vector(Connection(0, 2, 0.5, enabled = true, 1), Connection(2, 3, 0.5, enabled = true, 2), Connection(3, 1, 0.5, enabled = true, 3))
+    val network = new NeatNetwork(1, 1, 2, path)
+    (1 to 20).foreach { seed =>
+      val tracker = new InnovationTracker(3)
+      val grown = network.addConnection(tracker, new Random(seed))
+      assert(grown.parameterCount == 4)
+      assert(grown.connections.last.innovation == 4)
+      assert(tracker.lastInnovation == 4)
+      assert(!grown.connections.exists(c => c.from == 3 && c.to == 2))
+    }
+  }
+
+  test("a network with only cyclic candidates left is unchanged and takes no innovation") {
+    val tracker = new InnovationTracker(6)
+    val network = new NeatNetwork(1, 1, 2, Vector(
+      Connection(0, 2, 0.5, enabled = true, 1), Connection(2, 3, 0.5, enabled = true, 2), Connection(3, 1, 0.5, enabled = true, 3),
+      Connection(0, 1, 0.5, enabled = true, 4), Connection(0, 3, 0.5, enabled = true, 5), Connection(2, 1, 0.5, enabled = true, 6)
+    ))
+    assert(network.addConnection(tracker, new Random(1)) eq network)
+    assert(tracker.lastInnovation == 6)
+  }
+
+  test("genome round trip preserves outputs") {
+    val network = new NeatNetwork(2, 1, 0, connections).addNode(new InnovationTracker(2), new Random(1))
+    val in = Array(0.3, 0.7)
+    assert(NeatNetwork.fromGenome(network.genome, new InnovationTracker).process(in).toSeq == network.process(in).toSeq)
+  }
+}
@@ -0,0 +1,28 @@
+package geneuron.neuron
+
+import geneuron.creature.{Creature, SensorConfig}
+
+import scala.util.Random
+
+/**
+ * Rough timing of per-row process against processBatch on a creature-sized cluster;
+ * run with sbt "test:runMain geneuron.neuron.NeuronBenchmark [rows] [rounds]"
+ */
+object NeuronBenchmark {
+  def main(args: Array[String]): Unit = {
+    val rows = args.headOption.fold(1000)(_.toInt)
+    val rounds = args.lift(1).fold(200)(_.toInt)
+    val random = new Random(1)
+    val layers = Creature.neuronLayers(SensorConfig())
+    val cluster = new NeuronCluster(layers, Creature.randomGene(random))
+    val batch = Array.fill(rows)(Array.fill(layers.head)(random.nextDouble()))
+    def time(name: String)(f: => Unit): Unit = {
+      (1 to rounds / 10 + 1).foreach(_ => f)
+      val start = System.nanoTime
+      (1 to rounds).foreach(_ => f)
+      println("%-8s %10.3f ms per %d rows".format(name, (System.nanoTime - start) / 1e6 / rounds, rows))
+    }
+    time("process")(batch.map(cluster.process))
+    time("batch")(cluster.processBatch(batch))
+  }
+}
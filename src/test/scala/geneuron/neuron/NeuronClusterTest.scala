@@ -0,0 +1,54 @@
+package geneuron.neuron
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class NeuronClusterTest extends FunSuite {
+  val random = new Random(1)
+  val layers = Seq(4, 5, 3)
+  def randomCluster(activation: Activation = Activation.Sigmoid): NeuronCluster = {
+    new NeuronCluster(layers, Array.fill(NeuronCluster.parameterCount(layers))(random.nextDouble() * 2 - 1), activation)
+  }
+
+  test("toJson and fromJson reproduce identical outputs") {
+    val cluster = randomCluster(Activation.Tanh)
+    val restored = NeuronCluster.fromJson(cluster.toJson)
+    val in = Array.fill(4)(random.nextDouble())
+    assert(restored.layers == cluster.layers)
+    assert(restored.activation == Activation.Tanh)
+    assert(restored.process(in).toSeq == cluster.process(in).toSeq)
+  }
+
+  test("malformed JSON is rejected with MalformedNeuronException") {
+    intercept[MalformedNeuronException](NeuronCluster.fromJson("{"))
+    intercept[MalformedNeuronException](NeuronCluster.fromJson("""{"layers":[2,1],"weights":[[[1.0],[1.0]]],"biases":[[1.0,2.0]]}"""))
+  }
+
+  test("a batch gives the same rows as processing each input") {
+    val cluster = randomCluster()
+    val rows = Array.fill(16)(Array.fill(4)(random.nextDouble()))
+    val batch = cluster.processBatch(rows)
+    assert(batch.length == rows.length)
+    (batch zip rows.map(cluster.process)).foreach { case (b, p) =>
+      assert(b.length == p.length)
+      (b zip p).foreach { case (x, y) => assert(math.abs(x - y) < 1e-12) }
+    }
+    assert(cluster.processBatch(Array.empty).isEmpty)
+  }
+
+  test("JSON over the depth budget is rejected with MalformedNeuronException") {
+    val layers = Seq.fill(NeuronCluster.maxDepth + 2)(1)
+    val json = "{\"layers\":" + layers.mkString("[", ",", "]") +
+      ",\"weights\":" + Seq.fill(layers.length - 1)("[[0.5]]").mkString("[", ",", "]") +
+      ",\"biases\":" + Seq.fill(layers.length - 1)("[0.5]").mkString("[", ",", "]") + "}"
+    intercept[MalformedNeuronException](NeuronCluster.fromJson(json))
+  }
+
+  test("networks over the parameter or depth budget are rejected") {
+    val gene = Array.fill(NeuronCluster.parameterCount(layers))(0.5)
+    assert(new NeuronCluster(layers, gene, maxParameters = NeuronCluster.parameterCount(layers)).parameterCount == 43)
+    intercept[IllegalArgumentException](new NeuronCluster(layers, gene, maxParameters = NeuronCluster.parameterCount(layers) - 1))
+    intercept[IllegalArgumentException](new NeuronCluster(layers, gene, maxDepth = 1))
+  }
+}
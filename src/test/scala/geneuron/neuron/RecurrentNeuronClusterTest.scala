@@ -0,0 +1,47 @@
+package geneuron.neuron
+
+import org.scalatest.FunSuite
+
+import scala.util.Random
+
+class RecurrentNeuronClusterTest extends FunSuite {
+  val random = new Random(1)
+  val layers = Seq(4, 5, 3)
+  def randomGene: Array[Double] = Array.fill(RecurrentNeuronCluster.parameterCount(layers))(random.nextDouble() * 2 - 1)
+
+  test("each layer has outs*(ins+outs+1) parameters") {
+    assert(RecurrentNeuronCluster.parameterCount(layers) == 5 * (4 + 5 + 1) + 3 * (5 + 3 + 1))
+    assert(new RecurrentNeuronCluster(layers, randomGene).parameterCount == 77)
+  }
+
+  test("the same input twice gives different outputs until reset") {
+    val cluster = new RecurrentNeuronCluster(layers, randomGene)
+    val in = Array.fill(4)(random.nextDouble())
+    val first = cluster.process(in).toSeq
+    val second = cluster.process(in).toSeq
+    assert(first != second)
+    cluster.reset()
+    assert(cluster.process(in).toSeq == first)
+  }
+
+  test("genome round trip preserves outputs over a sequence") {
+    val gene = randomGene
+    val cluster = new RecurrentNeuronCluster(layers, gene, Activation.Tanh)
+    assert(cluster.genome.toSeq == gene.toSeq)
+    val restored = new RecurrentNeuronCluster(layers, cluster.genome, Activation.Tanh)
+    val ins = Seq.fill(5)(Array.fill(4)(random.nextDouble()))
+    assert(ins.map(restored.process(_).toSeq) == ins.map(cluster.process(_).toSeq))
+  }
+
+  test("batches are rejected because outputs depend on earlier inputs") {
+    val cluster = new RecurrentNeuronCluster(layers, randomGene)
+    intercept[UnsupportedOperationException](cluster.processBatch(Array(Array.fill(4)(0.5))))
+  }
+
+  test("networks over the parameter or depth budget are rejected") {
+    val gene = randomGene
+    assert(new RecurrentNeuronCluster(layers, gene, maxParameters = gene.length).parameterCount == gene.length)
+    intercept[IllegalArgumentException](new RecurrentNeuronCluster(layers, gene, maxParameters = gene.length - 1))
+    intercept[IllegalArgumentException](new RecurrentNeuronCluster(layers, gene, maxDepth = 1))
+  }
+}